@@ -1,7 +1,7 @@
 //! Cryptographic signature keys storage API
 
 use namada_core::storage;
-use namada_storage::{Result, ResultExt, StorageRead, StorageWrite};
+use namada_storage::{Error, Result, ResultExt, StorageRead, StorageWrite};
 
 use super::*;
 
@@ -79,6 +79,40 @@ where
     Ok(AccountPublicKeysMap::from_iter(public_keys))
 }
 
+/// Get the public keys controlling an account, ordered by their index, and
+/// the account's signature threshold.
+///
+/// An implicit account is controlled by the single key from which its address
+/// is derived with a threshold of 1. The key is only returned once it's been
+/// revealed. An established account is controlled by its explicit key set.
+pub fn account_keys<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<(Vec<common::PublicKey>, u8)>
+where
+    S: StorageRead,
+{
+    match owner {
+        Address::Implicit(_) => Ok((public_keys(storage, owner)?, 1)),
+        Address::Established(_) => {
+            if !exists(storage, owner)? {
+                return Err(Error::new_alloc(format!(
+                    "Established account {owner} doesn't exist"
+                )));
+            }
+            let threshold = threshold(storage, owner)?.ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Missing signature threshold of account {owner}"
+                ))
+            })?;
+            Ok((public_keys(storage, owner)?, threshold))
+        }
+        Address::Internal(_) => Err(Error::new_alloc(format!(
+            "Internal address {owner} isn't controlled by keys"
+        ))),
+    }
+}
+
 /// Check if a user account exists in storage
 pub fn exists<S>(storage: &S, owner: &Address) -> Result<bool>
 where
@@ -120,3 +154,44 @@ where
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::key::RefTo;
+    use namada_core::key::testing::{common_sk_from_simple_seed, keypair_1};
+    use namada_storage::testing::TestStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_implicit_account_keys() {
+        let mut storage = TestStorage::default();
+        let pk = keypair_1().ref_to();
+        let owner: Address = (&pk).into();
+
+        // No keys before the PK is revealed
+        assert_eq!(account_keys(&storage, &owner).unwrap(), (vec![], 1));
+
+        reveal_pk(&mut storage, &pk).unwrap();
+        assert_eq!(account_keys(&storage, &owner).unwrap(), (vec![pk], 1));
+    }
+
+    #[test]
+    fn test_established_account_keys() {
+        let mut storage = TestStorage::default();
+        let owner = established_address_1();
+
+        // The account doesn't exist yet
+        assert!(account_keys(&storage, &owner).is_err());
+
+        let pks: Vec<_> = (0..3)
+            .map(|seed| common_sk_from_simple_seed(seed).ref_to())
+            .collect();
+        storage
+            .write(&storage::Key::validity_predicate(&owner), Hash::default())
+            .unwrap();
+        init_account_storage(&mut storage, &owner, &pks, 2).unwrap();
+        assert_eq!(account_keys(&storage, &owner).unwrap(), (pks, 2));
+    }
+}
@@ -110,9 +110,18 @@ pub struct Shell {
     /// Tx WASM compilation in-memory cache maximum size in bytes.
     /// When not set, defaults to 1/6 of the available memory.
     pub tx_wasm_compilation_cache_bytes: Option<u64>,
+    /// When set to `true`, the VP and tx WASMs found in the wasm directory
+    /// are compiled into the compilation caches at node startup.
+    pub warm_up_wasm_compilation_cache: Option<bool>,
     /// When set, will limit the how many block heights in the past can the
     /// storage be queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// When set, the events of only this many most recent block heights are
+    /// retained in the event log.
+    pub event_log_retained_heights: Option<u64>,
+    /// When set to `true`, the reads of storage values from the write log and
+    /// from the storage are counted and logged for every committed block.
+    pub record_read_stats: Option<bool>,
     /// Use the [`Ledger::db_dir()`] method to read the value.
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
@@ -150,8 +159,11 @@ impl Ledger {
                 block_cache_bytes: None,
                 vp_wasm_compilation_cache_bytes: None,
                 tx_wasm_compilation_cache_bytes: None,
+                warm_up_wasm_compilation_cache: None,
                 // Default corresponds to 1 hour of past blocks at 1 block/sec
                 storage_read_past_height_limit: Some(3600),
+                event_log_retained_heights: None,
+                record_read_stats: None,
                 db_dir: DB_DIR.into(),
                 cometbft_dir: COMETBFT_DIR.into(),
                 action_at_height: None,
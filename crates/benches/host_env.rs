@@ -1,7 +1,7 @@
 use criterion::{Criterion, criterion_group, criterion_main};
 use namada_apps_lib::account::AccountPublicKeysMap;
 use namada_apps_lib::collections::{HashMap, HashSet};
-use namada_apps_lib::storage::DB;
+use namada_apps_lib::storage::{DB, DBIter};
 use namada_apps_lib::token::{Amount, Transfer};
 use namada_apps_lib::tx::Authorization;
 use namada_apps_lib::wallet::defaults;
@@ -248,6 +248,93 @@ fn storage_read(c: &mut Criterion) {
     group.finish();
 }
 
+// Benchmarks the iteration of a large prefix with different prefetch batch
+// sizes
+fn storage_iter_prefix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("storage_iter_prefix");
+    let bench_shell = BenchShell::default();
+    let mut shell = bench_shell.write();
+
+    let prefix = storage::Key::parse("bench_iter_prefix").unwrap();
+    for i in 0..10_000_u64 {
+        let key = prefix.push(&format!("{i:05}")).unwrap();
+        let value: Vec<u8> = (0..256).map(|_| rand::random()).collect();
+        shell.state.db_write(&key, &value).unwrap();
+    }
+
+    for batch_size in [0, 64 * 1024, 2 * 1024 * 1024] {
+        group.bench_function(format!("batch size: {batch_size}"), |b| {
+            b.iter(|| {
+                shell
+                    .state
+                    .db()
+                    .iter_prefix_with_batch(Some(&prefix), batch_size)
+                    .count()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+// Benchmarks the merged iteration of a prefix over the write log and the
+// storage, with different ratios of keys in the write log and in the storage
+fn storage_iter_prefix_merged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("storage_iter_prefix_merged");
+    let bench_shell = BenchShell::default();
+    let mut shell = bench_shell.write();
+
+    for prefix_size in [100_u64, 1_000, 10_000] {
+        for layout in ["all storage", "all write log", "half with deletes"] {
+            let prefix = storage::Key::parse(format!(
+                "bench_iter_merged_{prefix_size}_{}",
+                layout.replace(' ', "_")
+            ))
+            .unwrap();
+
+            let mut yielded_keys = 0_u64;
+            for i in 0..prefix_size {
+                let key = prefix.push(&format!("{i:05}")).unwrap();
+                let value: Vec<u8> = (0..32).map(|_| rand::random()).collect();
+                let in_storage = match layout {
+                    "all storage" => true,
+                    "all write log" => false,
+                    _ => i % 2 == 0,
+                };
+                if in_storage {
+                    shell.state.db_write(&key, &value).unwrap();
+                    // Delete every other key of the storage in the write log
+                    if layout == "half with deletes" && i % 4 == 0 {
+                        let _ =
+                            shell.state.write_log_mut().delete(&key).unwrap();
+                        continue;
+                    }
+                } else {
+                    let _ =
+                        shell.state.write_log_mut().write(&key, value).unwrap();
+                }
+                yielded_keys += 1;
+            }
+            // Extract the throughput, together with the wall-time, so that we
+            // can then invert it to calculate the desired metric (time/key)
+            group.throughput(criterion::Throughput::Elements(yielded_keys));
+
+            group.bench_function(
+                format!("keys: {prefix_size}, layout: {layout}"),
+                |b| {
+                    b.iter(|| {
+                        storage::iter_prefix_bytes(&shell.state, &prefix)
+                            .unwrap()
+                            .count()
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
 fn write_log_write(c: &mut Criterion) {
     let mut group = c.benchmark_group("write_log_write");
     let bench_shell = BenchShell::default();
@@ -337,6 +424,8 @@ criterion_group!(
     untrusted_wasm_validation,
     write_log_read,
     storage_read,
+    storage_iter_prefix,
+    storage_iter_prefix_merged,
     write_log_write,
     storage_write,
 );
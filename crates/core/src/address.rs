@@ -19,7 +19,9 @@ use sha2::{Digest, Sha256};
 use crate::ethereum_events::EthAddress;
 use crate::ibc::IbcTokenHash;
 use crate::key::PublicKeyHash;
-use crate::{impl_display_and_from_str_via_format, key, string_encoding};
+use crate::{
+    impl_display_and_from_str_via_format, key, storage, string_encoding,
+};
 
 /// The length of an established [`Address`] encoded with Borsh.
 pub const ESTABLISHED_ADDRESS_BYTES_LEN: usize = 21;
@@ -37,6 +39,9 @@ pub const HASH_HEX_LEN: usize = 40;
 /// Length of a trimmed hash of an address.
 pub const HASH_LEN: usize = 20;
 
+/// The length of an [`Address`] encoded with [`Address::to_bridge_bytes`].
+pub const BRIDGE_ADDRESS_BYTES_LEN: usize = 32;
+
 /// SHA-256 hash len
 ///
 /// ```
@@ -71,6 +76,33 @@ pub const ETH_BRIDGE: Address = Address::Internal(InternalAddress::EthBridge);
 /// never committed to DB
 pub const TEMP_STORAGE: Address =
     Address::Internal(InternalAddress::TempStorage);
+/// Internal storage write rate limit address
+pub const WRITE_RATE_LIMIT: Address =
+    Address::Internal(InternalAddress::WriteRateLimit);
+
+/// The canonical list of the internal addresses that are not derived from any
+/// data, i.e. all the [`InternalAddress`] variants except for the IBC, ERC20
+/// and NUT tokens, paired with their [`Address`].
+pub fn internal_addresses() -> Vec<(InternalAddress, Address)> {
+    [
+        InternalAddress::PoS,
+        InternalAddress::PosSlashPool,
+        InternalAddress::Parameters,
+        InternalAddress::Ibc,
+        InternalAddress::Governance,
+        InternalAddress::EthBridge,
+        InternalAddress::EthBridgePool,
+        InternalAddress::Multitoken,
+        InternalAddress::Pgf,
+        InternalAddress::Masp,
+        InternalAddress::ReplayProtection,
+        InternalAddress::TempStorage,
+        InternalAddress::WriteRateLimit,
+    ]
+    .into_iter()
+    .map(|internal| (internal.clone(), Address::Internal(internal)))
+    .collect()
+}
 
 /// Error from decoding address from string
 pub type DecodeError = string_encoding::DecodeError;
@@ -147,6 +179,9 @@ impl From<raw::Address<'_, raw::Validated>> for Address {
             raw::Discriminant::ReplayProtection => {
                 Address::Internal(InternalAddress::ReplayProtection)
             }
+            raw::Discriminant::WriteRateLimit => {
+                Address::Internal(InternalAddress::WriteRateLimit)
+            }
         }
     }
 }
@@ -253,6 +288,13 @@ impl<'addr> From<&'addr Address> for raw::Address<'addr, raw::Validated> {
                 .validate()
                 .expect("This raw address is valid")
             }
+            Address::Internal(InternalAddress::WriteRateLimit) => {
+                raw::Address::from_discriminant(
+                    raw::Discriminant::WriteRateLimit,
+                )
+                .validate()
+                .expect("This raw address is valid")
+            }
         }
     }
 }
@@ -299,6 +341,44 @@ impl Address {
         }
     }
 
+    /// Encode an address into a fixed-size 32 bytes field for the Ethereum
+    /// bridge. The encoding is the raw address (a discriminant byte followed
+    /// by the 20 bytes of the address' data, i.e. the bytes that are also
+    /// encoded with Bech32m) left-padded with zeros, the same way that
+    /// Ethereum left-pads its 20 bytes addresses into 32 bytes words.
+    ///
+    /// The encoding is lossless, so there are no collisions between distinct
+    /// addresses and the address can be recovered with
+    /// [`Address::from_bridge_bytes`].
+    pub fn to_bridge_bytes(&self) -> [u8; BRIDGE_ADDRESS_BYTES_LEN] {
+        let raw_addr: raw::Address<'_, _> = self.into();
+        let mut output = [0u8; BRIDGE_ADDRESS_BYTES_LEN];
+        output[BRIDGE_ADDRESS_BYTES_LEN - raw::ADDR_ENCODING_LEN..]
+            .copy_from_slice(&raw_addr.to_bytes());
+        output
+    }
+
+    /// Decode an address from its Ethereum bridge encoding produced by
+    /// [`Address::to_bridge_bytes`].
+    pub fn from_bridge_bytes(
+        bytes: &[u8; BRIDGE_ADDRESS_BYTES_LEN],
+    ) -> Result<Self> {
+        let (padding, raw_addr) =
+            bytes.split_at(BRIDGE_ADDRESS_BYTES_LEN - raw::ADDR_ENCODING_LEN);
+        if padding.iter().any(|&byte| byte != 0) {
+            return Err(DecodeError::InvalidInnerEncoding(
+                "Invalid bridge address padding".to_string(),
+            ));
+        }
+        string_encoding::Format::decode_bytes(raw_addr)
+    }
+
+    /// Get the storage key prefix of this address' subspace, under which all
+    /// of the account's storage keys live.
+    pub fn to_db_prefix(&self) -> storage::Key {
+        storage::Key::from(storage::KeySeg::to_db_key(self))
+    }
+
     fn pretty_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_pretty_string())
     }
@@ -615,6 +695,8 @@ pub enum InternalAddress {
     /// Address with temporary storage is used to pass data from txs to VPs
     /// which is never committed to DB
     TempStorage,
+    /// Storage write rate limit
+    WriteRateLimit,
 }
 
 impl Display for InternalAddress {
@@ -638,6 +720,7 @@ impl Display for InternalAddress {
                 Self::Masp => "MASP".to_string(),
                 Self::ReplayProtection => "ReplayProtection".to_string(),
                 Self::TempStorage => "TempStorage".to_string(),
+                Self::WriteRateLimit => "WriteRateLimit".to_string(),
             }
         )
     }
@@ -662,11 +745,68 @@ impl InternalAddress {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use proptest::prelude::*;
 
     use super::*;
     use crate::borsh::BorshSerializeExt;
 
+    #[test]
+    fn test_internal_addresses() {
+        // Exhaustive, so that a new internal address can't be left out
+        fn is_listed(internal: &InternalAddress) -> bool {
+            match internal {
+                InternalAddress::PoS
+                | InternalAddress::PosSlashPool
+                | InternalAddress::Parameters
+                | InternalAddress::Ibc
+                | InternalAddress::Governance
+                | InternalAddress::EthBridge
+                | InternalAddress::EthBridgePool
+                | InternalAddress::Multitoken
+                | InternalAddress::Pgf
+                | InternalAddress::Masp
+                | InternalAddress::ReplayProtection
+                | InternalAddress::TempStorage
+                | InternalAddress::WriteRateLimit => true,
+                InternalAddress::IbcToken(_)
+                | InternalAddress::Erc20(_)
+                | InternalAddress::Nut(_) => false,
+            }
+        }
+
+        let addresses = internal_addresses();
+        let unique: BTreeSet<_> = addresses.iter().cloned().collect();
+        assert_eq!(unique.len(), addresses.len());
+        assert_eq!(addresses.len(), 13);
+        for (internal, address) in &addresses {
+            assert!(is_listed(internal));
+            assert_eq!(address, &Address::Internal(internal.clone()));
+        }
+        for expected in [
+            POS,
+            POS_SLASH_POOL,
+            PARAMETERS,
+            IBC,
+            GOV,
+            ETH_BRIDGE,
+            MULTITOKEN,
+            PGF,
+            MASP,
+            TEMP_STORAGE,
+            WRITE_RATE_LIMIT,
+        ] {
+            assert_eq!(
+                addresses
+                    .iter()
+                    .filter(|(_, address)| address == &expected)
+                    .count(),
+                1
+            );
+        }
+    }
+
     /// Run `cargo test gen_established_address -- --nocapture` to generate a
     /// new established address.
     #[test]
@@ -687,6 +827,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bridge_bytes() {
+        let established = testing::established_address_1();
+        let implicit = testing::gen_implicit_address();
+        for address in [&established, &implicit] {
+            let bytes = address.to_bridge_bytes();
+            assert_eq!(Address::from_bridge_bytes(&bytes).unwrap(), *address);
+            // The raw hash is at the end of the encoding
+            assert_eq!(
+                HEXUPPER.encode(&bytes[BRIDGE_ADDRESS_BYTES_LEN - HASH_LEN..]),
+                address.raw_hash().unwrap()
+            );
+        }
+        assert_ne!(established.to_bridge_bytes(), implicit.to_bridge_bytes());
+
+        // Non-zero padding is rejected
+        let mut bytes = established.to_bridge_bytes();
+        bytes[0] = 1;
+        assert!(Address::from_bridge_bytes(&bytes).is_err());
+
+        // An invalid discriminant is rejected
+        let mut bytes = established.to_bridge_bytes();
+        bytes[BRIDGE_ADDRESS_BYTES_LEN - raw::ADDR_ENCODING_LEN] = u8::MAX;
+        assert!(Address::from_bridge_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_address_serde_serialize() {
         let original_address =
@@ -714,6 +880,15 @@ mod tests {
             assert_eq!(address, decoded);
         }
 
+        #[test]
+        /// Check that the Ethereum bridge encoding of all the address types
+        /// decodes back to the same value.
+        fn test_bridge_bytes_roundtrip(address in testing::arb_address()) {
+            let bytes = address.to_bridge_bytes();
+            let decoded = Address::from_bridge_bytes(&bytes).unwrap();
+            assert_eq!(address, decoded);
+        }
+
         #[test]
         fn test_established_address_bytes_length(address in testing::arb_established_address()) {
             let address = Address::Established(address);
@@ -905,8 +1080,9 @@ pub mod testing {
             InternalAddress::Masp => {}
             InternalAddress::Multitoken => {}
             InternalAddress::ReplayProtection => {}
-            InternalAddress::TempStorage => {} /* Add new addresses in the
-                                                * `prop_oneof` below. */
+            InternalAddress::TempStorage => {}
+            InternalAddress::WriteRateLimit => {} /* Add new addresses in the
+                                                   * `prop_oneof` below. */
         };
         prop_oneof![
             Just(InternalAddress::PoS),
@@ -924,6 +1100,7 @@ pub mod testing {
             Just(InternalAddress::Masp),
             Just(InternalAddress::ReplayProtection),
             Just(InternalAddress::TempStorage),
+            Just(InternalAddress::WriteRateLimit),
         ]
     }
 
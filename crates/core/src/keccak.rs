@@ -2,8 +2,8 @@
 //! hash function in a way that is compatible with smart contracts
 //! on Ethereum.
 
-use std::fmt;
 use std::str::FromStr;
+use std::{fmt, io};
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use data_encoding::HEXUPPER;
@@ -30,6 +30,11 @@ pub enum TryFromError {
     FromStringError(data_encoding::DecodeError),
 }
 
+/// Error for an invalid Ethereum function signature
+#[derive(Error, Debug)]
+#[error("Invalid Ethereum function signature {0:?}")]
+pub struct InvalidSignatureError(pub String);
+
 /// Represents a Keccak hash.
 #[derive(
     Clone,
@@ -170,6 +175,86 @@ pub fn keccak_hash<T: AsRef<[u8]>>(bytes: T) -> KeccakHash {
     KeccakHash(output)
 }
 
+/// Hash the contents of a reader using Keccak, reading the input in chunks
+/// rather than loading it into memory. Equivalent to [`keccak_hash`] of all
+/// the bytes read.
+pub fn keccak_hash_reader<R: io::Read>(
+    mut reader: R,
+) -> io::Result<KeccakHash> {
+    const CHUNK_SIZE: usize = 8 * 1024;
+
+    let mut output = [0; 32];
+    let mut chunk = [0; CHUNK_SIZE];
+
+    let mut hasher = Keccak::v256();
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => hasher.update(&chunk[..len]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    hasher.finalize(&mut output);
+
+    Ok(KeccakHash(output))
+}
+
+/// Hash a 32 byte array using Keccak. Equivalent to [`keccak_hash`].
+#[inline]
+pub fn keccak_hash_32(bytes: &[u8; 32]) -> KeccakHash {
+    keccak_hash_array(bytes)
+}
+
+/// Hash a fixed-size byte array using Keccak. Equivalent to
+/// [`keccak_hash`].
+#[inline]
+pub fn keccak_hash_array<const N: usize>(bytes: &[u8; N]) -> KeccakHash {
+    let mut output = [0; 32];
+
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+
+    KeccakHash(output)
+}
+
+/// Compute the Ethereum function selector of a canonical function signature,
+/// e.g. `transfer(address,uint256)`, that is the first 4 bytes of its Keccak
+/// hash. The signature must be a function name followed by its parenthesized
+/// parameter types, without any whitespace.
+pub fn eth_selector(signature: &str) -> Result<[u8; 4], InvalidSignatureError> {
+    let invalid = || InvalidSignatureError(signature.to_owned());
+    let (name, params) = signature.split_once('(').ok_or_else(invalid)?;
+    let is_valid_name = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    let params = params.strip_suffix(')').ok_or_else(invalid)?;
+    let is_valid_params = params.chars().all(|c| {
+        c.is_ascii_alphanumeric() || matches!(c, ',' | '[' | ']' | '(' | ')')
+    });
+    let mut depth = 0_u32;
+    for c in params.chars() {
+        match c {
+            '(' => depth = depth.checked_add(1).ok_or_else(invalid)?,
+            ')' => depth = depth.checked_sub(1).ok_or_else(invalid)?,
+            _ => {}
+        }
+    }
+    if !is_valid_name || !is_valid_params || depth != 0 {
+        return Err(invalid());
+    }
+
+    let KeccakHash(hash) = keccak_hash(signature);
+    let mut selector = [0; 4];
+    selector.copy_from_slice(&hash[..4]);
+    Ok(selector)
+}
+
 impl Encode<1> for KeccakHash {
     fn tokenize(&self) -> [Token; 1] {
         [Token::FixedBytes(self.0.to_vec())]
@@ -180,6 +265,17 @@ impl Encode<1> for KeccakHash {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_keccak_hash_reader() {
+        let bytes: Vec<u8> =
+            (0..100_000_u32).map(|i| (i % 251) as u8).collect();
+        let hash = keccak_hash_reader(bytes.as_slice()).unwrap();
+        assert_eq!(hash, keccak_hash(&bytes));
+
+        let empty = keccak_hash_reader(std::io::empty()).unwrap();
+        assert_eq!(empty, keccak_hash([]));
+    }
+
     #[test]
     fn test_keccak_serde_roundtrip() {
         let mut hash = KeccakHash([0; 32]);
@@ -194,4 +290,57 @@ mod tests {
 
         assert_eq!(deserialized, hash);
     }
+
+    #[test]
+    fn test_eth_selector() {
+        assert_eq!(
+            eth_selector("transfer(address,uint256)").unwrap(),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+        assert_eq!(
+            eth_selector("balanceOf(address)").unwrap(),
+            [0x70, 0xa0, 0x82, 0x31]
+        );
+        assert_eq!(
+            eth_selector("totalSupply()").unwrap(),
+            [0x18, 0x16, 0x0d, 0xdd]
+        );
+
+        for invalid in [
+            "",
+            "transfer",
+            "(address)",
+            "1transfer(address)",
+            "transfer(address, uint256)",
+            "transfer(address,uint256",
+            "transfer(address))",
+            "transfer(address)x",
+        ] {
+            assert!(eth_selector(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_keccak_hash_array_matches_slice() {
+        fn check<const N: usize>() {
+            let mut bytes = [0u8; N];
+            let mut next = 0u8;
+            for byte in bytes.iter_mut() {
+                *byte = next;
+                next = next.wrapping_add(1);
+            }
+            assert_eq!(keccak_hash_array(&bytes), keccak_hash(bytes));
+        }
+
+        check::<0>();
+        check::<1>();
+        check::<20>();
+        check::<32>();
+        check::<64>();
+        check::<136>();
+        check::<200>();
+
+        let bytes = [0xab; 32];
+        assert_eq!(keccak_hash_32(&bytes), keccak_hash(bytes));
+    }
 }
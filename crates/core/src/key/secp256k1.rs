@@ -172,6 +172,47 @@ impl From<&PublicKey> for EthAddress {
     }
 }
 
+/// Verify a signature produced by an Ethereum wallet's `personal_sign` (EIP-191
+/// version `0x45`) over the given `msg`. The 65 bytes `sig` are `r || s || v`,
+/// where `v` may be either the raw recovery id or that value offset by 27.
+/// Returns `true` iff the public key recovered from the signature belongs to
+/// `expected_eth_addr`.
+pub fn verify_eth_personal_sign(
+    msg: &[u8],
+    sig: &[u8; 65],
+    expected_eth_addr: [u8; 20],
+) -> bool {
+    let v = match sig[64] {
+        v @ (0 | 1) => v,
+        27 => 0,
+        28 => 1,
+        _ => return false,
+    };
+    let Some(recovery_id) = RecoveryId::from_byte(v) else {
+        return false;
+    };
+    let Ok(signature) = k256::ecdsa::Signature::from_slice(&sig[..64]) else {
+        return false;
+    };
+
+    let mut prefixed_msg =
+        format!("\x19Ethereum Signed Message:\n{}", msg.len()).into_bytes();
+    prefixed_msg.extend_from_slice(msg);
+    let msg_hash = crate::keccak::keccak_hash(prefixed_msg);
+
+    match k256::ecdsa::VerifyingKey::recover_from_prehash(
+        &msg_hash.0,
+        &signature,
+        recovery_id,
+    ) {
+        Ok(vrf_key) => {
+            let pk = PublicKey(vrf_key.into());
+            EthAddress::from(&pk).0 == expected_eth_addr
+        }
+        Err(_) => false,
+    }
+}
+
 /// Secp256k1 secret key
 #[derive(Debug, Clone, BorshDeserializer)]
 pub struct SecretKey(pub Box<k256::SecretKey>);
@@ -703,4 +744,40 @@ mod test {
             .expect("Test failed");
         assert_eq!(sig, signature);
     }
+
+    /// Test vector of a `personal_sign` signature of "hello world" by the
+    /// well-known first Hardhat development account, as given in the viem
+    /// `signMessage` documentation
+    #[test]
+    fn test_verify_eth_personal_sign() {
+        let eth_addr: [u8; 20] = HEXLOWER
+            .decode(b"f39fd6e51aad88f6f4ce6ab8827279cfffb92266")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut sig: [u8; 65] = HEXLOWER
+            .decode(
+                b"a461f509887bd19e312c0c58467ce8ff8e300d3c1a90b608a760c5b80318ea\
+                  f15fe57c96f9175d6cd4daad4663763baa7e78836e067d0163e9a2ccf2ff75\
+                  3f5b1b",
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let msg = b"hello world";
+
+        assert!(verify_eth_personal_sign(msg, &sig, eth_addr));
+
+        // A different message or address must not verify
+        assert!(!verify_eth_personal_sign(b"hello world!", &sig, eth_addr));
+        assert!(!verify_eth_personal_sign(msg, &sig, [0; 20]));
+
+        // The raw recovery id is accepted in place of the offset one
+        sig[64] = 0;
+        assert!(verify_eth_personal_sign(msg, &sig, eth_addr));
+
+        // An invalid recovery byte is rejected
+        sig[64] = 29;
+        assert!(!verify_eth_personal_sign(msg, &sig, eth_addr));
+    }
 }
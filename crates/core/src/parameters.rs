@@ -25,6 +25,8 @@ use crate::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
     PartialOrd,
     Ord,
     Hash,
+    Serialize,
+    Deserialize,
     BorshSerialize,
     BorshDeserialize,
     BorshDeserializer,
@@ -70,6 +72,8 @@ pub struct Parameters {
     PartialOrd,
     Ord,
     Hash,
+    Serialize,
+    Deserialize,
     BorshSerialize,
     BorshDeserialize,
     BorshDeserializer,
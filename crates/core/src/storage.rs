@@ -310,6 +310,68 @@ impl FromStr for Key {
     }
 }
 
+/// A builder of a [`Key`] from typed segments. Unlike [`Key::push`], string
+/// segments are never re-interpreted as addresses, and any segment that
+/// contains the [`KEY_SEGMENT_SEPARATOR`], starts with the
+/// [`RESERVED_ADDRESS_PREFIX`] or contains an invalid escape sequence (see
+/// [`EscapedKeySeg`]) is rejected on [`KeyBuilder::build`].
+#[derive(Debug, Default)]
+pub struct KeyBuilder {
+    segments: Vec<DbKeySeg>,
+    error: Option<Error>,
+}
+
+impl KeyBuilder {
+    /// Append an address segment
+    #[must_use]
+    pub fn address(mut self, addr: &Address) -> Self {
+        self.segments.push(DbKeySeg::AddressSeg(addr.clone()));
+        self
+    }
+
+    /// Append a string segment
+    #[must_use]
+    pub fn string(mut self, seg: impl Into<String>) -> Self {
+        let seg = seg.into();
+        if self.error.is_none()
+            && (seg.contains(KEY_SEGMENT_SEPARATOR)
+                || seg.starts_with(RESERVED_ADDRESS_PREFIX)
+                || validate_escapes(&seg).is_err())
+        {
+            self.error = Some(Error::InvalidKeySeg(seg));
+        } else {
+            self.segments.push(DbKeySeg::StringSeg(seg));
+        }
+        self
+    }
+
+    /// Append a string segment that may contain any characters, escaped as
+    /// an [`EscapedKeySeg`]
+    #[must_use]
+    pub fn escaped(self, seg: impl Into<String>) -> Self {
+        self.segment(&EscapedKeySeg(seg.into()))
+    }
+
+    /// Append a segment of any other key segment type
+    #[must_use]
+    pub fn segment<T: KeySeg>(self, seg: &T) -> Self {
+        match seg.to_db_key() {
+            DbKeySeg::AddressSeg(addr) => self.address(&addr),
+            DbKeySeg::StringSeg(seg) => self.string(seg),
+        }
+    }
+
+    /// Build the key, failing on the first invalid segment
+    pub fn build(self) -> Result<Key> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(Key {
+                segments: self.segments,
+            }),
+        }
+    }
+}
+
 /// Storage keys that are utf8 encoded strings
 #[derive(Eq, Debug, PartialEq, Copy, Clone, Hash, BorshDeserializer)]
 pub struct StringKey {
@@ -448,7 +510,9 @@ impl From<TreeBytes> for Vec<u8> {
 }
 
 impl Key {
-    /// Parses string and returns a key
+    /// Parses string and returns a key. Fails if a segment contains an
+    /// invalid escape sequence (see [`EscapedKeySeg`]), so that every parsed
+    /// segment can be unescaped.
     pub fn parse(string: impl AsRef<str>) -> Result<Self> {
         let string = string.as_ref();
         if string.is_empty() {
@@ -456,16 +520,26 @@ impl Key {
         } else {
             let mut segments = Vec::new();
             for s in string.split(KEY_SEGMENT_SEPARATOR) {
+                validate_escapes(s)?;
                 segments.push(DbKeySeg::parse(s.to_owned())?);
             }
             Ok(Key { segments })
         }
     }
 
-    /// Returns a new key with segments of `Self` and the given segment
+    /// Start building a key from typed segments
+    pub fn builder() -> KeyBuilder {
+        KeyBuilder::default()
+    }
+
+    /// Returns a new key with segments of `Self` and the given segment. Fails
+    /// if the segment contains an invalid escape sequence, like
+    /// [`Key::parse`].
     pub fn push<T: KeySeg>(&self, other: &T) -> Result<Self> {
         let mut segments = self.segments.clone();
-        segments.push(DbKeySeg::parse(other.raw())?);
+        let raw = other.raw();
+        validate_escapes(&raw)?;
+        segments.push(DbKeySeg::parse(raw)?);
         Ok(Key { segments })
     }
 
@@ -540,6 +614,19 @@ impl Key {
         Some((KeyRef { segments: prefix }, last))
     }
 
+    /// Returns a copy of this key without any trailing empty segments, such
+    /// that keys differing only by trailing separators (e.g. `a/b` and
+    /// `a/b/`) are made equal. The first segment is always kept.
+    pub fn canonicalize(&self) -> Key {
+        let mut segments = self.segments.clone();
+        while segments.len() > 1
+            && matches!(segments.last(), Some(DbKeySeg::StringSeg(s)) if s.is_empty())
+        {
+            segments.pop();
+        }
+        Key { segments }
+    }
+
     /// Returns a key of the wasm code of the given hash
     pub fn wasm_code(code_hash: &Hash) -> Self {
         let mut segments =
@@ -783,6 +870,85 @@ impl KeySeg for String {
     }
 }
 
+/// The character introducing an escape sequence in an [`EscapedKeySeg`].
+pub const KEY_SEGMENT_ESCAPE: char = '%';
+
+/// The characters that are percent-escaped in an [`EscapedKeySeg`], as they
+/// would otherwise split the segment or change the way it's parsed.
+const ESCAPED_KEY_SEGMENT_CHARS: [char; 4] = [
+    KEY_SEGMENT_ESCAPE,
+    KEY_SEGMENT_SEPARATOR,
+    RESERVED_ADDRESS_PREFIX,
+    VP_KEY_PREFIX,
+];
+
+/// A string key segment which may contain any characters, including the
+/// [`KEY_SEGMENT_SEPARATOR`]. Reserved characters are percent-escaped (e.g.
+/// `/` becomes `%2F`) when the segment is converted into a [`DbKeySeg`] and
+/// unescaped when it's parsed back, so that arbitrary strings, like token
+/// names, cannot corrupt the keyspace.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EscapedKeySeg(pub String);
+
+/// Check that every [`KEY_SEGMENT_ESCAPE`] in the given raw key segment starts
+/// a valid escape sequence of an [`EscapedKeySeg`].
+fn validate_escapes(seg: &str) -> Result<()> {
+    if seg.contains(KEY_SEGMENT_ESCAPE) {
+        EscapedKeySeg::parse(seg.to_owned())?;
+    }
+    Ok(())
+}
+
+impl KeySeg for EscapedKeySeg {
+    fn parse(string: String) -> Result<Self> {
+        let mut unescaped = String::with_capacity(string.len());
+        let mut chars = string.chars();
+        while let Some(c) = chars.next() {
+            if c != KEY_SEGMENT_ESCAPE {
+                unescaped.push(c);
+                continue;
+            }
+            // Only the canonical, upper-case escapes produced by `raw` are
+            // accepted, so that parsing and re-encoding is lossless
+            let escaped = chars
+                .next()
+                .zip(chars.next())
+                .map(|(hi, lo)| format!("{hi}{lo}"))
+                .and_then(|hex| {
+                    u8::from_str_radix(&hex, 16)
+                        .ok()
+                        .filter(|byte| format!("{byte:02X}") == hex)
+                })
+                .map(char::from)
+                .filter(|c| ESCAPED_KEY_SEGMENT_CHARS.contains(c))
+                .ok_or_else(|| {
+                    Error::ParseKeySeg(format!(
+                        "Invalid escape sequence in key segment {string}"
+                    ))
+                })?;
+            unescaped.push(escaped);
+        }
+        Ok(Self(unescaped))
+    }
+
+    fn raw(&self) -> String {
+        let mut escaped = String::with_capacity(self.0.len());
+        for c in self.0.chars() {
+            if ESCAPED_KEY_SEGMENT_CHARS.contains(&c) {
+                escaped
+                    .push_str(&format!("{KEY_SEGMENT_ESCAPE}{:02X}", c as u32));
+            } else {
+                escaped.push(c);
+            }
+        }
+        escaped
+    }
+
+    fn to_db_key(&self) -> DbKeySeg {
+        DbKeySeg::StringSeg(self.raw())
+    }
+}
+
 impl KeySeg for BlockHeight {
     fn parse(string: String) -> Result<Self> {
         let h = string.parse::<u64>().map_err(|e| {
@@ -1141,9 +1307,10 @@ pub mod tests {
     proptest! {
         /// Tests that any key that doesn't contain reserved prefixes is valid.
         /// This test excludes key segments starting with `#` or `?`
-        /// because they are reserved for `Address` or a validity predicate.
+        /// because they are reserved for `Address` or a validity predicate,
+        /// and segments including `%`, which starts an escape sequence.
         #[test]
-        fn test_key_parse(s in "[^#?/][^/]*/[^#?/][^/]*/[^#?/][^/]*") {
+        fn test_key_parse(s in "[^#?/%][^/%]*/[^#?/%][^/%]*/[^#?/%][^/%]*") {
             let key = Key::parse(s.clone()).expect("cannot parse the string");
             assert_eq!(key.to_string(), s);
         }
@@ -1151,9 +1318,10 @@ pub mod tests {
         /// Tests that any key that doesn't contain reserved prefixes and
         /// separators is valid. This test excludes key segments including `/`
         /// or starting with `#` or `?` because they are reserved for separator,
-        /// `Address` or validity predicate.
+        /// `Address` or validity predicate, and segments including `%`, which
+        /// starts an escape sequence.
         #[test]
-        fn test_key_push(s in "[^#?/][^/]*") {
+        fn test_key_push(s in "[^#?/%][^/%]*") {
             let addr = address::testing::established_address_1();
             let key = Key::from(addr.to_db_key()).push(&s).expect("cannot push the segment");
             assert_eq!(key.segments[1].raw(), s);
@@ -1309,6 +1477,19 @@ pub mod tests {
         assert_eq!(key.to_string(), target);
     }
 
+    #[test]
+    fn test_key_canonicalize() {
+        let key = Key::parse("a/b").unwrap();
+        assert_eq!(Key::parse("a/b/").unwrap().canonicalize(), key);
+        assert_eq!(Key::parse("a/b//").unwrap().canonicalize(), key);
+        assert_eq!(key.canonicalize(), key);
+        assert_ne!(Key::parse("a/b/").unwrap(), key);
+
+        // The first segment is never dropped
+        let key = Key::parse("/").unwrap();
+        assert_eq!(key.canonicalize().segments.len(), 1);
+    }
+
     #[test]
     fn test_key_push_valid() {
         let addr = address::testing::established_address_1();
@@ -1345,6 +1526,87 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_key_builder() {
+        let token = address::testing::established_address_1();
+        let owner = address::testing::established_address_2();
+        let key = Key::builder()
+            .address(&token)
+            .string("tokens")
+            .string("red")
+            .address(&owner)
+            .build()
+            .expect("cannot build the key");
+        let expected = Key::parse(format!(
+            "{}/tokens/red/{}",
+            KeySeg::raw(&token),
+            KeySeg::raw(&owner)
+        ))
+        .expect("cannot parse the string");
+        assert_eq!(key, expected);
+
+        let key = Key::builder()
+            .address(&token)
+            .segment(&BlockHeight(1))
+            .build()
+            .expect("cannot build the key");
+        assert_eq!(
+            key,
+            Key::from(token.to_db_key()).with_segment(BlockHeight(1))
+        );
+
+        for seg in ["tokens/red", "#tokens"] {
+            match Key::builder()
+                .address(&token)
+                .string(seg)
+                .address(&owner)
+                .build()
+                .expect_err("unexpectedly succeeded")
+            {
+                Error::InvalidKeySeg(s) => assert_eq!(s, seg),
+                _ => panic!("unexpected error happens"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_escaped_key_seg_roundtrip() {
+        let token = address::testing::established_address_1();
+        for name in ["tokens/red", "100%", "%2F", "#red", "?", "plain", ""] {
+            let seg = EscapedKeySeg(name.to_owned());
+            let raw = seg.raw();
+            assert!(!raw.contains(KEY_SEGMENT_SEPARATOR));
+            assert_eq!(EscapedKeySeg::parse(raw).unwrap(), seg);
+
+            // The escaped segment survives a round-trip through a key
+            let key = Key::builder()
+                .address(&token)
+                .escaped(name)
+                .string("balance")
+                .build()
+                .expect("cannot build the key");
+            let parsed = Key::parse(key.to_string()).unwrap();
+            assert_eq!(parsed, key);
+            assert_eq!(parsed.segments.len(), 3);
+            assert_eq!(
+                EscapedKeySeg::parse(parsed.segments[1].raw()).unwrap(),
+                seg
+            );
+        }
+        assert_eq!(EscapedKeySeg("a/b%".to_owned()).raw(), "a%2Fb%25");
+
+        // Invalid and non-canonical (lower-case) escape sequences are rejected,
+        // including in parsed keys and string segments
+        for raw in ["%", "%2", "%ZZ", "%41", "%2f", "a%2fb", "%+F"] {
+            assert!(EscapedKeySeg::parse(raw.to_owned()).is_err());
+            assert!(Key::parse(format!("a/{raw}")).is_err());
+            assert!(Key::parse("a").unwrap().push(&raw.to_owned()).is_err());
+            assert!(
+                Key::builder().address(&token).string(raw).build().is_err()
+            );
+        }
+    }
+
     proptest! {
         /// Ensure that addresses in storage keys preserve the order of the
         /// addresses.
@@ -134,6 +134,23 @@ impl Amount {
         }
     }
 
+    /// Encode this [`Amount`] as a big-endian `uint256`, as expected by the
+    /// Ethereum bridge contracts.
+    pub fn to_eth_u256_be(&self) -> [u8; 32] {
+        self.raw.to_big_endian()
+    }
+
+    /// Decode an [`Amount`] from a big-endian `uint256` coming from
+    /// Ethereum. Values above [`Amount::max_signed`] are rejected, since
+    /// they cannot be represented as a balance [`Change`].
+    pub fn from_eth_u256_be(bytes: [u8; 32]) -> Result<Self, AmountParseError> {
+        let raw = Uint::from_big_endian(&bytes);
+        if raw > uint::MAX_SIGNED_VALUE {
+            return Err(AmountParseError::InvalidRange);
+        }
+        Ok(Self { raw })
+    }
+
     /// Zero [`Amount`].
     pub fn zero() -> Self {
         Self::default()
@@ -425,6 +442,28 @@ impl From<Denomination> for u8 {
     }
 }
 
+/// Human readable metadata of a token, for wallets to render balances.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct TokenMetadata {
+    /// The name of the token
+    pub name: String,
+    /// The ticker symbol of the token
+    pub symbol: String,
+    /// The number of decimal places of the token
+    pub decimals: Denomination,
+}
+
 /// An amount with its denomination.
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
@@ -1414,4 +1453,31 @@ mod tests {
             assert_eq!(amount.raw.as_u128(), val);
         }
     }
+
+    #[test]
+    fn test_token_amount_eth_u256_be() {
+        for amount in [
+            Amount::zero(),
+            Amount::from_u64(1),
+            Amount::native_whole(1_000),
+            Amount::from_u128(u128::MAX),
+            Amount::max_signed(),
+        ] {
+            let bytes = amount.to_eth_u256_be();
+            assert_eq!(
+                Amount::from_eth_u256_be(bytes).expect("Test failed"),
+                amount
+            );
+        }
+
+        // the most significant byte comes first
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        assert_eq!(Amount::from_u64(1).to_eth_u256_be(), bytes);
+
+        assert_matches!(
+            Amount::from_eth_u256_be(Amount::max().to_eth_u256_be()),
+            Err(AmountParseError::InvalidRange)
+        );
+    }
 }
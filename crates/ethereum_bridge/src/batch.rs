@@ -0,0 +1,121 @@
+//! Canonical encoding of the Ethereum event batches signed by validators
+
+use namada_core::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_core::ethereum_events::{EthereumEvent, GetEventNonce, Uint};
+use namada_core::keccak::{KeccakHash, keccak_hash};
+use namada_macros::BorshDeserializer;
+#[cfg(feature = "migrations")]
+use namada_migrations::*;
+
+/// A nonced batch of [`EthereumEvent`]s, whose digest is signed by
+/// validators.
+///
+/// Both signing and signature verification must go through
+/// [`EthEventBatch::digest`], such that they agree byte for byte on
+/// the signed data, e.g. when checked with
+/// [`crate::verify_validator_signature`].
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Hash,
+    Ord,
+    Clone,
+    Debug,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+// NOTE: Avoid changing the order of the elements in this struct,
+// since it would change the digest of every batch.
+pub struct EthEventBatch {
+    /// Monotonically increasing nonce of the batch
+    pub nonce: Uint,
+    /// The events in the batch, in the order they were observed
+    pub events: Vec<EthereumEvent>,
+}
+
+impl GetEventNonce for EthEventBatch {
+    #[inline]
+    fn get_event_nonce(&self) -> Uint {
+        self.nonce
+    }
+}
+
+impl EthEventBatch {
+    /// The canonical encoding of the batch, i.e. its Borsh serialization.
+    #[inline]
+    pub fn encode(&self) -> Vec<u8> {
+        self.serialize_to_vec()
+    }
+
+    /// Keccak hash of the canonical encoding of the batch. This is the
+    /// digest signed by validators.
+    #[inline]
+    pub fn digest(&self) -> KeccakHash {
+        keccak_hash(self.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::ethereum_events::TransferToNamada;
+    use namada_core::ethereum_events::testing::DAI_ERC20_ETH_ADDRESS;
+    use namada_core::token::Amount;
+
+    use super::*;
+
+    /// Test vectors for the canonical encoding of [`EthEventBatch`].
+    #[test]
+    fn test_eth_event_batch_digest_vectors() {
+        let empty = EthEventBatch {
+            nonce: 0.into(),
+            events: vec![],
+        };
+        assert_eq!(empty.encode(), vec![0u8; 36]);
+        assert_eq!(
+            empty.digest().to_string(),
+            "74723BC3EFAF59D897623890AE3912B9BE3C4C67CCEE3FFCF10B36406C722C1B"
+        );
+
+        let valset_update = EthereumEvent::ValidatorSetUpdate {
+            nonce: 1.into(),
+            bridge_validator_hash: KeccakHash([1; 32]),
+            governance_validator_hash: KeccakHash([2; 32]),
+        };
+        let single = EthEventBatch {
+            nonce: 1.into(),
+            events: vec![valset_update.clone()],
+        };
+        assert_eq!(
+            single.digest().to_string(),
+            "EC7F0A5EEB7065D3FB60306F063D3249B5014C1C9C12A88A69C6864AD3C350A4"
+        );
+
+        let multi = EthEventBatch {
+            nonce: 2.into(),
+            events: vec![
+                valset_update,
+                EthereumEvent::TransfersToNamada {
+                    nonce: 2.into(),
+                    transfers: vec![TransferToNamada {
+                        amount: Amount::from_u64(100),
+                        asset: DAI_ERC20_ETH_ADDRESS,
+                        receiver:
+                            namada_core::address::testing::established_address_1(
+                            ),
+                    }],
+                },
+            ],
+        };
+        assert_eq!(
+            multi.digest().to_string(),
+            "373709538731F5A0C861730ACEB18CE90F9EF2B48D23F7353B1D7324062F8D85"
+        );
+
+        // The event order is part of the digest
+        let mut reordered = multi.clone();
+        reordered.events.reverse();
+        assert_ne!(multi.digest(), reordered.digest());
+    }
+}
@@ -17,7 +17,9 @@
     clippy::print_stderr
 )]
 
+pub mod batch;
 pub mod event;
+pub mod nonce;
 pub mod oracle;
 pub mod protocol;
 pub mod storage;
@@ -25,5 +27,8 @@ pub mod storage;
 pub mod test_utils;
 pub mod vp;
 
+pub use batch::EthEventBatch;
 pub use namada_core::address::ETH_BRIDGE as ADDRESS;
 pub use namada_trans_token as token;
+pub use nonce::derive_nonce;
+pub use protocol::validation::bridge_pool_roots::verify_validator_signature;
@@ -1,18 +1,60 @@
 //! Bridge pool roots validation.
 
-use namada_core::chain::BlockHeight;
-use namada_core::keccak::keccak_hash;
+use namada_core::address::Address;
+use namada_core::chain::{BlockHeight, Epoch};
+use namada_core::keccak::{KeccakHash, keccak_hash};
+use namada_core::key::common;
 use namada_proof_of_stake::queries::{
     get_validator_eth_hot_key, get_validator_protocol_key,
 };
-use namada_state::{DB, DBIter, StorageHasher, StorageRead, WlState};
+use namada_state::{
+    DB, DBIter, Result as StorageResult, StorageHasher, StorageRead, WlState,
+};
 use namada_systems::governance;
-use namada_tx::{SignableEthMessage, Signed};
+use namada_tx::{SignableEthMessage, Signed, verify_standalone_sig};
 use namada_vote_ext::bridge_pool_roots;
 
 use super::VoteExtensionError;
 use crate::storage::eth_bridge_queries::EthBridgeQueries;
 
+/// Verify a validator's signature over the Ethereum bridge `batch_root`
+/// (e.g. the keccak hash of the bridge pool root and nonce, or an
+/// [`EthEventBatch::digest`](crate::EthEventBatch::digest)), made with the
+/// validator's Ethereum hot key at the given epoch.
+///
+/// Returns `false` if the signature is invalid or the validator has no
+/// Ethereum hot key in storage.
+pub fn verify_validator_signature<S, Gov>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+    batch_root: &KeccakHash,
+    sig: &common::Signature,
+) -> StorageResult<bool>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let Some(pk) =
+        get_validator_eth_hot_key::<_, Gov>(storage, validator, epoch)?
+    else {
+        return Ok(false);
+    };
+    let verified =
+        verify_standalone_sig::<_, SignableEthMessage>(batch_root, &pk, sig)
+            .map_err(|err| {
+                tracing::debug!(
+                    ?err,
+                    ?sig,
+                    ?pk,
+                    %validator,
+                    "Failed to verify a validator's signature over an \
+                     Ethereum bridge root"
+                );
+            });
+    Ok(verified.is_ok())
+}
+
 /// Validates a vote extension issued at the provided
 /// block height signing over the latest Ethereum bridge
 /// pool root and nonce.
@@ -112,25 +154,93 @@ where
         .ethbridge_queries()
         .get_bridge_pool_nonce_at_height(ext.data.block_height)
         .to_bytes();
-    let signed = Signed::<_, SignableEthMessage>::new_from(
-        keccak_hash([bp_root, nonce].concat()),
-        ext.data.sig.clone(),
+    let signed_root = keccak_hash([bp_root, nonce].concat());
+    let is_valid_sig = verify_validator_signature::<_, Gov>(
+        state,
+        validator,
+        ext_height_epoch,
+        &signed_root,
+        &ext.data.sig,
+    )
+    .expect(
+        "Reading a validator's Ethereum hot key from storage shouldn't fail",
     );
-    let pk =
-        get_validator_eth_hot_key::<_, Gov>(state, validator, ext_height_epoch)
-            .ok()
-            .flatten()
-            .expect("A validator should have an Ethereum hot key in storage.");
-    signed.verify(&pk).map_err(|err| {
+    if !is_valid_sig {
         tracing::debug!(
-            ?err,
-            ?signed.sig,
-            ?pk,
+            ?ext.data.sig,
             %validator,
             "Failed to verify the signature of an Bridge pool root \
             issued by some validator."
         );
-        VoteExtensionError::InvalidBPRootSig
-    })?;
+        return Err(VoteExtensionError::InvalidBPRootSig);
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address;
+
+    use super::*;
+    use crate::test_utils::{self, GovStore};
+
+    #[test]
+    fn test_verify_validator_signature() {
+        let (state, keys) = test_utils::setup_default_storage();
+        let (validator, _) = test_utils::default_validator();
+        let hot_key = &keys[&validator].eth_bridge;
+        let root = keccak_hash([1; 32]);
+        let sig =
+            Signed::<_, SignableEthMessage>::new(hot_key, root.clone()).sig;
+
+        // A valid signature
+        assert!(
+            verify_validator_signature::<_, GovStore<_>>(
+                &state,
+                &validator,
+                Epoch(0),
+                &root,
+                &sig,
+            )
+            .unwrap()
+        );
+
+        // A signature from the wrong signer
+        let other_key = &keys[&validator].protocol;
+        let wrong_sig =
+            Signed::<_, SignableEthMessage>::new(other_key, root.clone()).sig;
+        assert!(
+            !verify_validator_signature::<_, GovStore<_>>(
+                &state,
+                &validator,
+                Epoch(0),
+                &root,
+                &wrong_sig,
+            )
+            .unwrap()
+        );
+        let non_validator = address::testing::established_address_2();
+        assert!(
+            !verify_validator_signature::<_, GovStore<_>>(
+                &state,
+                &non_validator,
+                Epoch(0),
+                &root,
+                &sig,
+            )
+            .unwrap()
+        );
+
+        // A signature over the wrong root
+        assert!(
+            !verify_validator_signature::<_, GovStore<_>>(
+                &state,
+                &validator,
+                Epoch(0),
+                &keccak_hash([2; 32]),
+                &sig,
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! A buffer of events emitted within a transaction, with optional
+//! deduplication.
+//!
+//! Events are buffered in emission order. Emitting through [`EmitEvents`]
+//! always keeps repeated events, as these may be semantically meaningful,
+//! while [`EventBuffer::push_dedup`] can be used to opt into suppressing
+//! exact duplicates (i.e. events with the same type, level and attributes).
+
+use crate::{EmitEvents, Event, EventToEmit};
+
+/// A buffer of [events](Event), in emission order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventBuffer {
+    events: Vec<Event>,
+}
+
+impl EventBuffer {
+    /// Create a new empty [`EventBuffer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push an event to the buffer, keeping any duplicates.
+    pub fn push(&mut self, event: impl EventToEmit) {
+        self.events.push(event.into());
+    }
+
+    /// Push an event to the buffer, unless an identical event has already
+    /// been buffered. Returns `true` if the event was added.
+    pub fn push_dedup(&mut self, event: impl EventToEmit) -> bool {
+        let event = event.into();
+        if self.events.contains(&event) {
+            return false;
+        }
+        self.events.push(event);
+        true
+    }
+
+    /// Returns the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events have been buffered.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Iterate over the buffered events, in emission order.
+    pub fn iter(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter()
+    }
+
+    /// Return the buffered events, in emission order.
+    pub fn into_events(self) -> Vec<Event> {
+        self.events
+    }
+}
+
+impl EmitEvents for EventBuffer {
+    #[inline]
+    fn emit<E>(&mut self, event: E)
+    where
+        E: EventToEmit,
+    {
+        self.push(event);
+    }
+
+    /// Emit a batch of [events](Event).
+    fn emit_many<B, E>(&mut self, event_batch: B)
+    where
+        B: IntoIterator<Item = E>,
+        E: EventToEmit,
+    {
+        self.events.extend(event_batch.into_iter().map(Into::into));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extend::{ComposeEvent, Log};
+    use crate::{EventLevel, EventTypeBuilder};
+
+    fn event(log: &str) -> Event {
+        Event::new(
+            EventTypeBuilder::new_with_type("test").build(),
+            EventLevel::Tx,
+        )
+        .with(Log(log.to_owned()))
+        .into()
+    }
+
+    #[test]
+    fn test_push_dedup() {
+        let mut buffer = EventBuffer::new();
+        assert!(buffer.push_dedup(event("a")));
+        assert!(!buffer.push_dedup(event("a")));
+        assert!(buffer.push_dedup(event("b")));
+        assert!(!buffer.push_dedup(event("b")));
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.into_events(), vec![event("a"), event("b")]);
+    }
+
+    #[test]
+    fn test_emit_keeps_duplicates() {
+        let mut buffer = EventBuffer::new();
+        buffer.emit(event("a"));
+        buffer.emit_many([event("a"), event("b")]);
+        assert_eq!(buffer.len(), 3);
+
+        // Deduplication only applies to the events pushed with it
+        assert!(!buffer.push_dedup(event("b")));
+        assert_eq!(buffer.len(), 3);
+    }
+}
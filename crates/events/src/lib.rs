@@ -17,6 +17,7 @@
     clippy::print_stderr
 )]
 
+pub mod buffer;
 pub mod extend;
 #[cfg(any(test, feature = "testing"))]
 pub mod testing;
@@ -392,6 +393,21 @@ impl Event {
         })
     }
 
+    /// Check if the values of this [`Event`]'s attributes start with
+    /// the corresponding prefixes in `prefixes`.
+    #[inline]
+    pub fn has_attrs_with_prefixes<A: extend::AttributesMap>(
+        &self,
+        prefixes: &A,
+    ) -> bool {
+        prefixes.iter_attributes().all(|(key, prefix)| {
+            match self.attributes.get(key) {
+                Some(v) => v.starts_with(prefix),
+                None => false,
+            }
+        })
+    }
+
     /// Delete the given attribute.
     #[inline]
     pub fn delete_attribute<DATA>(&mut self)
@@ -277,7 +277,10 @@ where
     Ok(())
 }
 
-fn compute_proposal_votes<S, PoS>(
+/// Collect the votes of the given proposal with the voting power of the voters
+/// at the given epoch. The votes involving validators that are not active at
+/// the epoch are skipped.
+pub fn compute_proposal_votes<S, PoS>(
     storage: &S,
     proposal_id: u64,
     epoch: Epoch,
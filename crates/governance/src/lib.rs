@@ -36,13 +36,16 @@ pub mod storage;
 pub mod utils;
 pub mod vp;
 
-pub use finalize_block::finalize_block;
+pub use finalize_block::{compute_proposal_votes, finalize_block};
 use namada_state::{StorageRead, StorageWrite};
 pub use namada_systems::governance::*;
 use parameters::GovernanceParameters;
 pub use storage::proposal::{InitProposalData, ProposalType, VoteProposalData};
 pub use storage::vote::ProposalVote;
-pub use storage::{init_proposal, is_proposal_accepted, vote_proposal};
+pub use storage::{
+    content_commitment, init_proposal, is_proposal_accepted, next_proposal_id,
+    vote_proposal,
+};
 
 /// The governance internal address
 pub const ADDRESS: Address = address::GOV;
@@ -12,6 +12,7 @@ struct Keys {
     author: &'static str,
     proposal_type: &'static str,
     content: &'static str,
+    content_commitment: &'static str,
     start_epoch: &'static str,
     end_epoch: &'static str,
     activation_epoch: &'static str,
@@ -127,6 +128,24 @@ pub fn is_content_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is content commitment key
+pub fn is_content_commitment_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(commitment),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && commitment == Keys::VALUES.content_commitment =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is balance key
 pub fn is_balance_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -358,6 +377,15 @@ pub fn get_content_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get key of proposal content commitment
+pub fn get_content_commitment_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.content_commitment.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal author
 pub fn get_author_key(id: u64) -> Key {
     proposal_prefix()
@@ -10,10 +10,11 @@ pub mod vote;
 use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
-use namada_core::borsh::BorshDeserialize;
+use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
 use namada_core::chain::Epoch;
 use namada_core::collections::HashSet;
 use namada_core::hash::Hash;
+use namada_core::keccak::{KeccakHash, keccak_hash};
 use namada_core::token;
 use namada_state::{Error, Result, StorageRead, StorageWrite, iter_prefix};
 use namada_systems::trans_token;
@@ -22,11 +23,28 @@ use crate::ADDRESS as governance_address;
 use crate::parameters::GovernanceParameters;
 use crate::storage::keys as governance_keys;
 use crate::storage::proposal::{
-    InitProposalData, ProposalType, StorageProposal, VoteProposalData,
+    InitProposalData, ProposalContent, ProposalType, StorageProposal,
+    VoteProposalData,
 };
 use crate::storage::vote::ProposalVote;
 use crate::utils::{ProposalResult, Vote};
 
+/// Get the id that will be assigned to the next proposal. The counter is read
+/// through the write log, so proposals initialized earlier in the same block
+/// are accounted for.
+pub fn next_proposal_id<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let counter_key = governance_keys::get_counter_key();
+    storage.read(&counter_key)?.ok_or_else(|| {
+        Error::new_const(
+            "Storage should have been initialized with an initial governance \
+             proposal id",
+        )
+    })
+}
+
 /// A proposal creation transaction.
 pub fn init_proposal<S, TransToken>(
     storage: &mut S,
@@ -39,13 +57,14 @@ where
     TransToken: trans_token::Write<S>,
 {
     let counter_key = governance_keys::get_counter_key();
-    let proposal_id = storage.read(&counter_key)?.expect(
-        "Storage should have been initialized with an initial governance \
-         proposal id",
-    );
+    let proposal_id = next_proposal_id(storage)?;
 
     let content_key = governance_keys::get_content_key(proposal_id);
-    // The content should have been already encoded with borsh
+    // The content should have been already encoded with borsh, so its hash
+    // matches the `content_commitment` of the decoded content
+    let content_commitment_key =
+        governance_keys::get_content_commitment_key(proposal_id);
+    storage.write(&content_commitment_key, keccak_hash(&content))?;
     storage.write_bytes(&content_key, content)?;
 
     let author_key = governance_keys::get_author_key(proposal_id);
@@ -144,6 +163,24 @@ where
     storage.write(&proposal_result_key, proposal_result)
 }
 
+/// Compute the commitment to a proposal's content, i.e. the keccak hash of its
+/// Borsh serialization. The commitment is stored alongside the proposal, such
+/// that content fetched off-chain can be verified against it.
+pub fn content_commitment(content: &ProposalContent) -> KeccakHash {
+    keccak_hash(content.serialize_to_vec())
+}
+
+/// Read the content commitment of a proposal by id from storage
+pub fn get_content_commitment<S>(
+    storage: &S,
+    id: u64,
+) -> Result<Option<KeccakHash>>
+where
+    S: StorageRead,
+{
+    storage.read(&governance_keys::get_content_commitment_key(id))
+}
+
 /// Read a proposal by id from storage
 pub fn get_proposal_by_id<S>(
     storage: &S,
@@ -357,3 +394,87 @@ where
 
     Ok(ids)
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::address;
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    #[test]
+    fn test_next_proposal_id() {
+        let mut state = TestState::default();
+        let author = address::testing::established_address_1();
+
+        // The counter must be initialized
+        assert!(next_proposal_id(&state).is_err());
+
+        GovernanceParameters::default()
+            .init_storage(&mut state)
+            .unwrap();
+        let native_token = state.get_native_token().unwrap();
+        namada_token::credit_tokens(
+            &mut state,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1_000),
+        )
+        .unwrap();
+        assert_eq!(next_proposal_id(&state).unwrap(), 0);
+
+        // Initialize two proposals in the same write log
+        let data = InitProposalData {
+            content: Hash::default(),
+            author: author.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(3),
+            voting_end_epoch: Epoch(9),
+            activation_epoch: Epoch(12),
+        };
+        let first = init_proposal::<_, namada_token::Store<_>>(
+            &mut state,
+            &data,
+            vec![],
+            None,
+        )
+        .unwrap();
+        assert_eq!(next_proposal_id(&state).unwrap(), 1);
+        let second = init_proposal::<_, namada_token::Store<_>>(
+            &mut state,
+            &data,
+            vec![],
+            None,
+        )
+        .unwrap();
+        assert_eq!((first, second), (0, 1));
+        assert_eq!(next_proposal_id(&state).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_content_commitment() {
+        let content: ProposalContent = [
+            ("title".to_owned(), "A proposal".to_owned()),
+            ("abstract".to_owned(), "Some changes".to_owned()),
+        ]
+        .into_iter()
+        .collect();
+
+        // Identical content yields the same commitment
+        assert_eq!(
+            content_commitment(&content),
+            content_commitment(&content.clone())
+        );
+        // The commitment matches the hash of the encoded content written
+        // to storage
+        assert_eq!(
+            content_commitment(&content),
+            keccak_hash(content.serialize_to_vec())
+        );
+
+        // Changing a single field diverges
+        let mut changed = content.clone();
+        changed.insert("title".to_owned(), "Another proposal".to_owned());
+        assert_ne!(content_commitment(&content), content_commitment(&changed));
+    }
+}
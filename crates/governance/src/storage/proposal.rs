@@ -470,13 +470,16 @@ impl Display for PGFAction {
     }
 }
 
+/// The content of a proposal
+pub type ProposalContent = BTreeMap<String, String>;
+
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
 /// Proposal representation when fetched from the storage
 pub struct StorageProposal {
     /// The proposal id
     pub id: u64,
     /// The proposal content
-    pub content: BTreeMap<String, String>,
+    pub content: ProposalContent,
     /// The proposal author address
     pub author: Address,
     /// The proposal type
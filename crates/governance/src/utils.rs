@@ -17,6 +17,16 @@ use super::storage::proposal::ProposalType;
 use super::storage::vote::ProposalVote;
 
 /// Proposal status
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
 pub enum ProposalStatus {
     /// Pending proposal status
     Pending,
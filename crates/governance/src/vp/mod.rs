@@ -10,6 +10,7 @@ use borsh::BorshDeserialize;
 use namada_core::arith::checked;
 use namada_core::booleans::{BoolResultUnitExt, ResultBoolExt};
 use namada_core::chain::Epoch;
+use namada_core::keccak::{KeccakHash, keccak_hash};
 use namada_core::storage;
 use namada_systems::{proof_of_stake, trans_token as token};
 use namada_tx::BatchedTxRef;
@@ -143,6 +144,9 @@ where
                 (KeyType::CONTENT, Some(proposal_id)) => {
                     Self::is_valid_content_key(ctx, proposal_id)
                 }
+                (KeyType::CONTENT_COMMITMENT, Some(proposal_id)) => {
+                    Self::is_valid_content_commitment(ctx, proposal_id)
+                }
                 (KeyType::TYPE, Some(proposal_id)) => {
                     Self::is_valid_proposal_type(ctx, proposal_id)
                 }
@@ -213,6 +217,7 @@ where
             let mandatory_keys = BTreeSet::from([
                 counter_key.clone(),
                 gov_storage::get_content_key(counter),
+                gov_storage::get_content_commitment_key(counter),
                 gov_storage::get_author_key(counter),
                 gov_storage::get_proposal_type_key(counter),
                 gov_storage::get_funds_key(counter),
@@ -383,6 +388,38 @@ where
         Ok(())
     }
 
+    /// Validate a proposal content commitment
+    pub fn is_valid_content_commitment(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let commitment_key =
+            gov_storage::get_content_commitment_key(proposal_id);
+        let content_key = gov_storage::get_content_key(proposal_id);
+
+        let has_pre_commitment: bool = ctx.has_key_pre(&commitment_key)?;
+        if has_pre_commitment {
+            return Err(Error::new_alloc(format!(
+                "Proposal with id {proposal_id} already had a content \
+                 commitment written to storage."
+            )));
+        }
+
+        let commitment: KeccakHash =
+            Self::force_read(ctx, &commitment_key, ReadType::Post)?;
+        let post_content_bytes =
+            ctx.read_bytes_post(&content_key)?.unwrap_or_default();
+        if commitment != keccak_hash(post_content_bytes) {
+            let error = Error::new_alloc(format!(
+                "The content commitment of proposal {proposal_id} doesn't \
+                 match its content."
+            ));
+            tracing::info!("{error}");
+            return Err(error);
+        }
+        Ok(())
+    }
+
     /// Validate the proposal type
     pub fn is_valid_proposal_type(
         ctx: &'ctx CTX,
@@ -1091,6 +1128,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     CONTENT,
     #[allow(non_camel_case_types)]
+    CONTENT_COMMITMENT,
+    #[allow(non_camel_case_types)]
     PROPOSAL_CODE,
     #[allow(non_camel_case_types)]
     TYPE,
@@ -1125,6 +1164,8 @@ impl KeyType {
             Self::VOTE
         } else if gov_storage::is_content_key(key) {
             KeyType::CONTENT
+        } else if gov_storage::is_content_commitment_key(key) {
+            KeyType::CONTENT_COMMITMENT
         } else if gov_storage::is_proposal_type_key(key) {
             Self::TYPE
         } else if gov_storage::is_proposal_code_key(key) {
@@ -1168,6 +1209,7 @@ mod test {
     };
     use namada_core::borsh::BorshSerializeExt;
     use namada_core::chain::testing::get_dummy_header;
+    use namada_core::keccak::keccak_hash;
     use namada_core::key::RefTo;
     use namada_core::key::testing::keypair_1;
     use namada_core::parameters::Parameters;
@@ -1193,9 +1235,9 @@ mod test {
 
     use crate::storage::keys::{
         get_activation_epoch_key, get_author_key, get_committing_proposals_key,
-        get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
-        get_vote_proposal_key, get_voting_end_epoch_key,
-        get_voting_start_epoch_key,
+        get_content_commitment_key, get_content_key, get_counter_key,
+        get_funds_key, get_proposal_type_key, get_vote_proposal_key,
+        get_voting_end_epoch_key, get_voting_start_epoch_key,
     };
     use crate::{ADDRESS, ProposalType, ProposalVote};
 
@@ -1343,6 +1385,7 @@ mod test {
         let voting_start_epoch_key = get_voting_start_epoch_key(proposal_id);
         let activation_epoch_key = get_activation_epoch_key(proposal_id);
         let content_key = get_content_key(proposal_id);
+        let content_commitment_key = get_content_commitment_key(proposal_id);
         let author_key = get_author_key(proposal_id);
         let proposal_type_key = get_proposal_type_key(proposal_id);
         let funds_key = get_funds_key(proposal_id);
@@ -1353,6 +1396,7 @@ mod test {
             counter_key.clone(),
             funds_key.clone(),
             content_key.clone(),
+            content_commitment_key.clone(),
             author_key.clone(),
             proposal_type_key.clone(),
             voting_start_epoch_key.clone(),
@@ -1411,11 +1455,13 @@ mod test {
         let voting_start_epoch_key = get_voting_start_epoch_key(proposal_id);
         let activation_epoch_key = get_activation_epoch_key(proposal_id);
         let content_key = get_content_key(proposal_id);
+        let content_commitment_key = get_content_commitment_key(proposal_id);
         let author_key = get_author_key(proposal_id);
         let proposal_type_key = get_proposal_type_key(proposal_id);
         let funds_key = get_funds_key(proposal_id);
         let commiting_key =
             get_committing_proposals_key(proposal_id, activation_epoch);
+        let content = vec![1, 2, 3, 4];
 
         transfer(state, signer_address, &ADDRESS, funds);
 
@@ -1449,8 +1495,12 @@ mod test {
             .unwrap();
         let _ = state
             .write_log_mut()
-            .write(&content_key, vec![1, 2, 3, 4])
+            .write(
+                &content_commitment_key,
+                keccak_hash(&content).serialize_to_vec(),
+            )
             .unwrap();
+        let _ = state.write_log_mut().write(&content_key, content).unwrap();
         let _ = state
             .write_log_mut()
             .write(&author_key, signer_address.serialize_to_vec())
@@ -2136,6 +2186,91 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_governance_no_content_commitment_failed() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        // The content commitment is not part of the changed keys
+        let mut keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+        keys_changed.remove(&get_content_commitment_key(proposal_id));
+
+        let gas_meter =
+            RefCell::new(VpGasMeter::new_from_tx_meter(&TxGasMeter::new(
+                u64::MAX,
+                namada_parameters::get_gas_scale(&state).unwrap(),
+            )));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let tx_code = vec![];
+        let tx_data = vec![];
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(tx_code, None));
+        tx.set_data(Data::new(tx_data));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.header_hash()],
+            [(0, keypair_1())].into_iter().collect(),
+            None,
+        )));
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            activation_epoch,
+            &signer_address,
+            false,
+        );
+
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+    }
+
     #[test]
     fn test_governance_invalid_start_epoch_failed() {
         let mut state = init_storage();
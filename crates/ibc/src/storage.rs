@@ -262,6 +262,38 @@ pub fn commitment_key(
         .expect("Creating a key for the commitment shouldn't fail")
 }
 
+/// Returns a key prefix for the commitments of the packets sent on a channel
+pub fn commitment_prefix(port_id: &PortId, channel_id: &ChannelId) -> Key {
+    let path =
+        format!("commitments/ports/{port_id}/channels/{channel_id}/sequences");
+    ibc_key(path)
+        .expect("Creating a key prefix for the commitments shouldn't fail")
+}
+
+/// Returns the commitments of the packets sent on a channel that still await
+/// an acknowledgement or a timeout, ordered by sequence. The commitment of a
+/// packet is deleted once the packet is acknowledged or timed out.
+pub fn pending_packet_commitments<S>(
+    storage: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Vec<(Sequence, Vec<u8>)>>
+where
+    S: StorageRead,
+{
+    let prefix = commitment_prefix(port_id, channel_id);
+    let mut commitments = namada_state::iter_prefix_bytes(storage, &prefix)?
+        .map(|item| {
+            let (key, commitment) = item?;
+            let (_, _, sequence) = port_channel_sequence_id(&key)?;
+            Ok((sequence, commitment))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // The storage keys are ordered by the string of the sequence
+    commitments.sort_by_key(|(sequence, _)| *sequence);
+    Ok(commitments)
+}
+
 /// Returns a key for the receipt
 pub fn receipt_key(
     port_id: &PortId,
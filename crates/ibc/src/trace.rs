@@ -8,6 +8,7 @@ use ibc::apps::nft_transfer::types::{
 use ibc::apps::transfer::types::{PrefixedDenom, TracePath};
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::address::{Address, HASH_LEN, InternalAddress, SHA_HASH_LEN};
+use namada_core::hash::Hash;
 use namada_core::ibc::IbcTokenHash;
 use sha2::{Digest, Sha256};
 
@@ -43,6 +44,22 @@ pub fn calc_ibc_denom(trace: impl AsRef<str>) -> String {
     format!("ibc/{hash:X}")
 }
 
+/// Hash the ICS-20 denom trace made of the trace `path` (e.g.
+/// `transfer/channel-1/transfer/channel-0`) and the `base_denom` the same way
+/// as the counterparty chains do. The ICS-20 denom is `ibc/<hash>`. An empty
+/// path hashes the base denom alone.
+pub fn denom_trace_hash(path: &str, base_denom: &str) -> Hash {
+    let denom = if path.is_empty() {
+        calc_ibc_denom(base_denom)
+    } else {
+        calc_ibc_denom(format!("{path}/{base_denom}"))
+    };
+    denom
+        .strip_prefix("ibc/")
+        .and_then(|hash| Hash::from_str(hash).ok())
+        .expect("An ICS-20 denom must be the hex encoded hash of the trace")
+}
+
 /// Obtain the IbcToken with the hash from the given denom
 pub fn ibc_token(trace: impl AsRef<str>) -> Address {
     let hash = calc_ibc_token_hash(&trace);
@@ -135,3 +152,47 @@ pub fn is_receiver_chain_source(
         .as_ref()
         .starts_with(&format!("{src_port_id}/{src_channel_id}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denom_trace_hash() {
+        // The single hop vectors are well-known denoms, i.e. ATOM on Osmosis
+        // and OSMO on the Cosmos Hub. The multi-hop vectors are the SHA-256
+        // digests of their full trace.
+        let vectors = [
+            (
+                "transfer/channel-0",
+                "uatom",
+                "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            ),
+            (
+                "transfer/channel-141",
+                "uosmo",
+                "14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC",
+            ),
+            (
+                "transfer/channel-1/transfer/channel-0",
+                "uatom",
+                "FA0006F056DB6719B8C16C551FC392B62F5729978FC0B125AC9A432DBB2AA1A5",
+            ),
+            (
+                "transfer/channel-0/transfer/channel-42/transfer/channel-7",
+                "unam",
+                "5AAFCD63D72B801C84D790206DCDE10884FFF559E3116600BE5734C599FD9FB1",
+            ),
+        ];
+        for (path, base_denom, expected) in vectors {
+            let hash = denom_trace_hash(path, base_denom);
+            assert_eq!(hash.to_string(), expected);
+            assert_eq!(
+                format!("ibc/{hash}"),
+                calc_ibc_denom(format!("{path}/{base_denom}"))
+            );
+        }
+
+        assert_eq!(denom_trace_hash("", "unam"), Hash::sha256("unam"));
+    }
+}
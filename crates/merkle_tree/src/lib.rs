@@ -362,6 +362,33 @@ impl StoreType {
         }
     }
 
+    /// Get the key prefixes of all the keys committed to the store's sub-tree.
+    /// The prefixes of the account and Ethereum bridge pool sub-trees also
+    /// contain keys of other sub-trees. The base tree has no keys in the
+    /// subspace.
+    pub fn key_prefixes(&self) -> Vec<Key> {
+        let address_prefix =
+            |internal| Key::from(Address::Internal(internal).to_db_key());
+        match self {
+            Self::Base => vec![],
+            Self::Account => vec![Key::default()],
+            Self::Ibc => vec![address_prefix(InternalAddress::Ibc)],
+            Self::PoS => vec![
+                address_prefix(InternalAddress::PoS),
+                address_prefix(InternalAddress::PosSlashPool),
+            ],
+            Self::BridgePool => {
+                vec![address_prefix(InternalAddress::EthBridgePool)]
+            }
+            Self::NoDiff => {
+                vec![Key::from(NO_DIFF_KEY_PREFIX.to_string().to_db_key())]
+            }
+            Self::CommitData => {
+                vec![Key::from("commit_data".to_string().to_db_key())]
+            }
+        }
+    }
+
     /// Get the key prefix if the store type is for a provable subtree.
     /// Otherwise, returns None.
     pub fn provable_prefix(&self) -> Option<Key> {
@@ -9,14 +9,15 @@ use namada_sdk::address::{Address, InternalAddress};
 use namada_sdk::booleans::BoolResultUnitExt;
 use namada_sdk::chain::BlockHeight;
 use namada_sdk::collections::HashSet;
-use namada_sdk::events::EventLevel;
 use namada_sdk::events::extend::{
     ComposeEvent, Height as HeightAttr, InnerTxHash as InnerTxHashAttr,
     TxHash as TxHashAttr, UserAccount,
 };
+use namada_sdk::events::{Event, EventLevel};
 use namada_sdk::gas::{self, Gas, GasMetering, TxGasMeter, VpGasMeter};
 use namada_sdk::hash::Hash;
 use namada_sdk::parameters::get_gas_scale;
+use namada_sdk::parameters::storage::read_max_account_writes_per_block;
 use namada_sdk::state::{
     DB, DBIter, State, StorageHasher, StorageRead, TxWrites, WlState,
 };
@@ -34,12 +35,13 @@ use namada_sdk::tx::event::{MaspEvent, MaspEventKind, MaspTxRef};
 use namada_sdk::tx::{BatchedTxRef, IndexedTx, Tx, TxCommitments};
 use namada_sdk::validation::{
     EthBridgeNutVp, EthBridgePoolVp, EthBridgeVp, GovernanceVp, IbcVp, MaspVp,
-    MultitokenVp, NativeVpCtx, ParametersVp, PgfVp, PosVp,
+    MultitokenVp, NativeVpCtx, ParametersVp, PgfVp, PosVp, WriteRateLimitVp,
 };
 use namada_sdk::{governance, parameters, state, storage, token};
 use namada_vm::wasm::{TxCache, VpCache};
 use namada_vm::{self, WasmCacheAccess, wasm};
 use namada_vote_ext::EthereumTxData;
+use namada_vp::VpEnv;
 use namada_vp::native_vp::NativeVp;
 use namada_vp::state::ReadConversionState;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -83,6 +85,11 @@ pub enum Error {
     NativeVpError(state::Error),
     #[error("Access to an internal address {0:?} is forbidden")]
     AccessForbidden(InternalAddress),
+    #[error(
+        "The transaction changed {count} storage keys, exceeding the maximum \
+         of {max}"
+    )]
+    TooManyChangedKeys { count: usize, max: u64 },
 }
 
 impl Error {
@@ -1036,6 +1043,11 @@ where
         tx_wasm_cache,
     } = shell_params;
 
+    // Read before the tx is executed, so that a tx cannot raise its own limit
+    let max_changed_keys =
+        parameters::storage::read_max_tx_changed_keys(&*state)
+            .map_err(Error::StateError)?;
+
     let verifiers = execute_tx(
         wrapper_hash,
         batched_tx,
@@ -1046,7 +1058,9 @@ where
         tx_wasm_cache,
     )?;
 
-    let vps_result = check_vps(CheckVps {
+    check_changed_keys_limit(state, max_changed_keys)?;
+
+    let mut vps_result = check_vps(CheckVps {
         batched_tx,
         tx_index,
         state,
@@ -1057,7 +1071,8 @@ where
 
     let initialized_accounts = state.write_log().get_initialized_accounts();
     let changed_keys = state.write_log().get_keys();
-    let events = state.write_log_mut().take_events();
+    let mut events = state.write_log_mut().take_events();
+    events.append(&mut vps_result.events);
 
     Ok(BatchedTxResult {
         changed_keys,
@@ -1185,6 +1200,27 @@ where
     })
 }
 
+/// Reject a tx that changed more distinct storage keys than allowed by the
+/// `max_changed_keys` limit, i.e. the `max_tx_changed_keys` parameter read
+/// before the tx was executed, if it's set. This bounds the state growth per
+/// tx.
+fn check_changed_keys_limit<S>(
+    state: &S,
+    max_changed_keys: Option<u64>,
+) -> Result<()>
+where
+    S: State,
+{
+    let Some(max) = max_changed_keys else {
+        return Ok(());
+    };
+    let count = state.write_log().iter_changed_keys().count();
+    if usize::try_from(max).is_ok_and(|max| count > max) {
+        return Err(Error::TooManyChangedKeys { count, max });
+    }
+    Ok(())
+}
+
 /// Arguments to [`check_vps`].
 struct CheckVps<'a, S, CA>
 where
@@ -1214,10 +1250,20 @@ where
     S: 'static + ReadConversionState + State + Sync,
     CA: 'static + WasmCacheAccess + Sync,
 {
-    let (verifiers, keys_changed) = state
+    let (mut verifiers, keys_changed) = state
         .write_log()
         .verifiers_and_changed_keys(verifiers_from_tx);
 
+    // Trigger the write rate limit VP for txs that modify the storage of
+    // non-internal accounts. The VP reads the limit from the state prior to
+    // the tx and accepts the tx if it's not set.
+    if keys_changed
+        .iter()
+        .any(|key| key.fst_address().is_some_and(|owner| !owner.is_internal()))
+    {
+        verifiers.insert(Address::Internal(InternalAddress::WriteRateLimit));
+    }
+
     let (vps_result, vps_gas) = execute_vps(
         verifiers,
         keys_changed,
@@ -1251,7 +1297,7 @@ where
     S: 'static + ReadConversionState + State + Sync,
     CA: 'static + WasmCacheAccess + Sync,
 {
-    let vps_result = verifiers
+    let (mut vps_result, vps_gas) = verifiers
         .par_iter()
         .try_fold(
             || (VpsResult::default(), Gas::from(0)),
@@ -1291,6 +1337,7 @@ where
                             }
                             _ => Error::VpRunnerError(err),
                         })
+                        .map(|()| vec![])
                     }
                     Address::Internal(internal_addr) => {
                         let ctx = NativeVpCtx::new(
@@ -1312,7 +1359,8 @@ where
                                 &keys_changed,
                                 &verifiers,
                             )
-                            .map_err(Error::NativeVpError),
+                            .map_err(Error::NativeVpError)
+                            .map(|()| ctx.take_events()),
                             InternalAddress::Ibc => {
                                 let ibc = IbcVp::new(ctx);
                                 ibc.validate_tx(
@@ -1321,6 +1369,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ibc.ctx.take_events())
                             }
                             InternalAddress::Parameters => {
                                 ParametersVp::validate_tx(
@@ -1330,6 +1379,19 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
+                            }
+                            InternalAddress::WriteRateLimit => {
+                                read_max_account_writes_per_block(&ctx.pre())
+                                    .and_then(|max_writes| {
+                                        WriteRateLimitVp::validate_tx(
+                                            &ctx,
+                                            &keys_changed,
+                                            max_writes,
+                                        )
+                                    })
+                                    .map_err(Error::NativeVpError)
+                                    .map(|()| ctx.take_events())
                             }
                             InternalAddress::PosSlashPool => {
                                 Err(Error::AccessForbidden(
@@ -1344,6 +1406,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
                             }
                             InternalAddress::Pgf => PgfVp::validate_tx(
                                 &ctx,
@@ -1351,7 +1414,8 @@ where
                                 &keys_changed,
                                 &verifiers,
                             )
-                            .map_err(Error::NativeVpError),
+                            .map_err(Error::NativeVpError)
+                            .map(|()| ctx.take_events()),
                             InternalAddress::Multitoken => {
                                 MultitokenVp::validate_tx(
                                     &ctx,
@@ -1360,6 +1424,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
                             }
                             InternalAddress::Masp => MaspVp::validate_tx(
                                 &ctx,
@@ -1367,7 +1432,8 @@ where
                                 &keys_changed,
                                 &verifiers,
                             )
-                            .map_err(Error::NativeVpError),
+                            .map_err(Error::NativeVpError)
+                            .map(|()| ctx.take_events()),
                             InternalAddress::EthBridge => {
                                 EthBridgeVp::validate_tx(
                                     &ctx,
@@ -1376,6 +1442,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
                             }
                             InternalAddress::EthBridgePool => {
                                 EthBridgePoolVp::validate_tx(
@@ -1385,6 +1452,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
                             }
                             InternalAddress::Nut(_) => {
                                 EthBridgeNutVp::validate_tx(
@@ -1394,6 +1462,7 @@ where
                                     &verifiers,
                                 )
                                 .map_err(Error::NativeVpError)
+                                .map(|()| ctx.take_events())
                             }
                             internal_addr @ (InternalAddress::IbcToken(_)
                             | InternalAddress::Erc20(_)) => {
@@ -1408,6 +1477,7 @@ where
                                             internal_addr.clone(),
                                         )
                                     })
+                                    .map(|()| vec![])
                             }
                             InternalAddress::TempStorage => Err(
                                 // Temp storage changes must never be committed
@@ -1426,18 +1496,7 @@ where
                     }
                 };
 
-                tx_accepted.map_or_else(
-                    |err| {
-                        result
-                            .status_flags
-                            .insert(err.invalid_section_signature_flag());
-                        result.rejected_vps.insert(addr.clone());
-                        result.errors.push((addr.clone(), err.to_string()));
-                    },
-                    |()| {
-                        result.accepted_vps.insert(addr.clone());
-                    },
-                );
+                record_vp_result(&mut result, addr, tx_accepted);
 
                 // Execution of VPs can (and must) be short-circuited
                 // only in case of a gas overflow to prevent the
@@ -1464,7 +1523,38 @@ where
             |a, b| merge_vp_results(a, b, tx_gas_meter),
         )?;
 
-    Ok(vps_result)
+    drop_events_of_rejected_tx(&mut vps_result);
+    Ok((vps_result, vps_gas))
+}
+
+/// Record the outcome of a single VP in the VPs result. The events emitted by
+/// the VP are only kept if it accepted the tx.
+fn record_vp_result(
+    result: &mut VpsResult,
+    addr: &Address,
+    tx_accepted: Result<Vec<Event>>,
+) {
+    tx_accepted.map_or_else(
+        |err| {
+            result
+                .status_flags
+                .insert(err.invalid_section_signature_flag());
+            result.rejected_vps.insert(addr.clone());
+            result.errors.push((addr.clone(), err.to_string()));
+        },
+        |events| {
+            result.accepted_vps.insert(addr.clone());
+            result.events.extend(events);
+        },
+    );
+}
+
+/// Drop the events emitted by the VPs unless all of them accepted the tx, as
+/// the events are only attached to accepted txs.
+fn drop_events_of_rejected_tx(result: &mut VpsResult) {
+    if !result.rejected_vps.is_empty() {
+        result.events.clear();
+    }
 }
 
 /// Merge VP results from parallel runs
@@ -1480,6 +1570,8 @@ fn merge_vp_results(
     let mut errors = a.errors;
     errors.append(&mut b.errors);
     let status_flags = a.status_flags | b.status_flags;
+    let mut events = a.events;
+    events.append(&mut b.events);
 
     let vps_gas = a_gas
         .checked_add(b_gas)
@@ -1494,6 +1586,7 @@ fn merge_vp_results(
             rejected_vps,
             errors,
             status_flags,
+            events,
         },
         vps_gas,
     ))
@@ -1516,6 +1609,7 @@ mod tests {
     use namada_sdk::ethereum_events::{EthereumEvent, TransferToNamada};
     use namada_sdk::keccak::keccak_hash;
     use namada_sdk::key::RefTo;
+    use namada_sdk::storage::KeySeg;
     use namada_sdk::testing::{
         arb_tampered_inner_tx, arb_valid_signed_inner_tx,
     };
@@ -1525,6 +1619,7 @@ mod tests {
     use namada_test_utils::TestWasms;
     use namada_vote_ext::bridge_pool_roots::BridgePoolRootVext;
     use namada_vote_ext::ethereum_events::EthereumEventsVext;
+    use namada_vp::native_vp::write_rate_limit::WriteRateLimitEvent;
     use namada_vp::state::StorageWrite;
     use proptest::test_runner::{Config, TestRunner};
 
@@ -1740,6 +1835,157 @@ mod tests {
         assert!(matches!(result.unwrap_err(), Error::GasError(_)));
     }
 
+    /// Test that the events emitted by the native VPs are only kept in the
+    /// VPs result if all the VPs accept the tx
+    #[test]
+    fn test_native_vp_events() {
+        let (state, _validators) = test_utils::setup_default_storage();
+        let (vp_cache, _) = wasm::compilation_cache::common::testing::cache();
+
+        let mut tx = Tx::from_type(namada_sdk::tx::data::TxType::Raw);
+        tx.set_code(namada_sdk::tx::Code::new(vec![], None));
+        tx.set_data(namada_sdk::tx::Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX, get_gas_scale(&state).unwrap()),
+        ));
+        let keys_changed = BTreeSet::new();
+        let verifiers = BTreeSet::new();
+        let tx_index = TxIndex::default();
+        let event = Event::new(
+            namada_sdk::events::EventType::new("native-vp-test"),
+            EventLevel::Tx,
+        );
+
+        // Run a dummy native VP that emits an event before deciding on the
+        // tx
+        let run_vp = |addr: &Address, accept: bool| {
+            let ctx = NativeVpCtx::new(
+                addr,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_cache.clone(),
+            );
+            ctx.emit_event(event.clone()).unwrap();
+            let tx_accepted = if accept {
+                Ok(())
+            } else {
+                Err(Error::NativeVpError(state::Error::new_const("rejected")))
+            };
+            tx_accepted.map(|()| ctx.take_events())
+        };
+
+        let accepting = Address::Internal(InternalAddress::Governance);
+        let rejecting = Address::Internal(InternalAddress::Pgf);
+
+        let mut result = VpsResult::default();
+        record_vp_result(&mut result, &accepting, run_vp(&accepting, true));
+        assert!(result.accepted_vps.contains(&accepting));
+        assert_eq!(result.events, BTreeSet::from([event.clone()]));
+
+        let mut result = VpsResult::default();
+        record_vp_result(&mut result, &rejecting, run_vp(&rejecting, false));
+        assert!(result.rejected_vps.contains(&rejecting));
+        assert!(result.events.is_empty());
+
+        // The events of an accepting VP are dropped if another VP rejects the
+        // tx
+        let mut result = VpsResult::default();
+        record_vp_result(&mut result, &accepting, run_vp(&accepting, true));
+        record_vp_result(&mut result, &rejecting, run_vp(&rejecting, false));
+        drop_events_of_rejected_tx(&mut result);
+        assert!(result.accepted_vps.contains(&accepting));
+        assert!(result.events.is_empty());
+    }
+
+    /// Test that the write rate limit VP reports the accounts that used up
+    /// their writes for the block with an event, which is only kept if all
+    /// the VPs accept the tx
+    #[test]
+    fn test_write_rate_limit_events() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+        StorageWrite::write(
+            &mut state,
+            &parameters::storage::get_max_account_writes_per_block_key(),
+            1_u64,
+        )
+        .unwrap();
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(namada_sdk::tx::data::TxType::Raw);
+        tx.set_code(namada_sdk::tx::Code::new(vec![], None));
+        tx.set_data(namada_sdk::tx::Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let (mut vp_cache, _) =
+            wasm::compilation_cache::common::testing::cache();
+        let gas_meter =
+            TxGasMeter::new(u64::MAX, get_gas_scale(&state).unwrap());
+
+        let account = Address::Established([0xab; 20].into());
+        let key = |seg: &str| {
+            storage::Key::from(account.to_db_key())
+                .push(&seg.to_owned())
+                .unwrap()
+        };
+        let rate_limit = Address::Internal(InternalAddress::WriteRateLimit);
+        let slash_pool = Address::Internal(InternalAddress::PosSlashPool);
+
+        // The tx uses up the account's writes for the block, which the write
+        // rate limit VP reports with an event
+        let _ = state.write_log_mut().write(&key("a"), vec![1]).unwrap();
+        let keys_changed = state.write_log().get_keys();
+        let mut run_vps = |verifiers: BTreeSet<Address>| {
+            execute_vps(
+                verifiers,
+                keys_changed.clone(),
+                &batched_tx,
+                &TxIndex::default(),
+                &state,
+                &gas_meter,
+                &mut vp_cache,
+            )
+            .unwrap()
+            .0
+        };
+        let result = run_vps(BTreeSet::from([rate_limit.clone()]));
+        assert!(result.accepted_vps.contains(&rate_limit));
+        let event: Event = WriteRateLimitEvent {
+            account: account.clone(),
+            writes: 1,
+        }
+        .into();
+        assert_eq!(result.events, BTreeSet::from([event]));
+
+        // The event is dropped if another VP rejects the tx
+        let result =
+            run_vps(BTreeSet::from([rate_limit.clone(), slash_pool.clone()]));
+        assert!(result.accepted_vps.contains(&rate_limit));
+        assert!(result.rejected_vps.contains(&slash_pool));
+        assert!(result.events.is_empty());
+
+        // The event is dropped if the VP itself rejects the tx
+        let _ = state.write_log_mut().write(&key("b"), vec![2]).unwrap();
+        let result = execute_vps(
+            BTreeSet::from([rate_limit.clone()]),
+            state.write_log().get_keys(),
+            &batched_tx,
+            &TxIndex::default(),
+            &state,
+            &gas_meter,
+            &mut vp_cache,
+        )
+        .unwrap()
+        .0;
+        assert!(result.rejected_vps.contains(&rate_limit));
+        assert!(result.events.is_empty());
+    }
+
     // Test that the host function for signature verification we expose allows
     // the vps to detect a tx that has been tampered with
     #[test]
@@ -1825,4 +2071,61 @@ mod tests {
         });
         assert!(result.is_ok());
     }
+
+    /// Test that a tx that changes more distinct keys than allowed by the
+    /// `max_tx_changed_keys` parameter is rejected.
+    #[test]
+    fn test_changed_keys_limit() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+        let key = |seg: &str| storage::Key::parse(seg).unwrap();
+        let max_key = parameters::storage::get_max_tx_changed_keys_key();
+
+        // Without the parameter, there's no limit
+        assert_eq!(
+            parameters::storage::read_max_tx_changed_keys(&state).unwrap(),
+            None
+        );
+        for seg in ["a", "b", "c"] {
+            let _ = state.write_log_mut().write(&key(seg), vec![1]).unwrap();
+        }
+        assert!(check_changed_keys_limit(&state, None).is_ok());
+        state.write_log_mut().drop_tx();
+
+        StorageWrite::write(&mut state, &max_key, 2_u64).unwrap();
+        state.commit_block().unwrap();
+        let max =
+            parameters::storage::read_max_tx_changed_keys(&state).unwrap();
+        assert_eq!(max, Some(2));
+
+        // Under the limit, the same key changed twice counts once
+        let _ = state.write_log_mut().write(&key("a"), vec![1]).unwrap();
+        let _ = state.write_log_mut().write(&key("a"), vec![2]).unwrap();
+        let _ = state.write_log_mut().write(&key("b"), vec![3]).unwrap();
+        assert!(check_changed_keys_limit(&state, max).is_ok());
+
+        // Over the limit
+        let _ = state.write_log_mut().delete(&key("c")).unwrap();
+        assert!(matches!(
+            check_changed_keys_limit(&state, max),
+            Err(Error::TooManyChangedKeys { count: 3, max: 2 })
+        ));
+        state.write_log_mut().drop_tx();
+
+        // A tx raising its own limit is still held to the limit read before
+        // it was executed
+        let _ = state
+            .write_log_mut()
+            .write(&max_key, borsh::to_vec(&10_u64).unwrap())
+            .unwrap();
+        assert_eq!(
+            parameters::storage::read_max_tx_changed_keys(&state).unwrap(),
+            Some(10)
+        );
+        let _ = state.write_log_mut().write(&key("a"), vec![1]).unwrap();
+        let _ = state.write_log_mut().write(&key("b"), vec![2]).unwrap();
+        assert!(matches!(
+            check_changed_keys_limit(&state, max),
+            Err(Error::TooManyChangedKeys { count: 3, max: 2 })
+        ));
+    }
 }
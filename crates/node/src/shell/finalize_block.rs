@@ -21,7 +21,7 @@ use namada_sdk::state::{
 use namada_sdk::storage::{BlockHeader, BlockResults, Epoch};
 use namada_sdk::tx::data::protocol::ProtocolTxType;
 use namada_sdk::tx::data::{VpStatusFlags, compute_inner_tx_hash};
-use namada_sdk::tx::event::{Batch, Code};
+use namada_sdk::tx::event::{Batch, Code, TriggeredVps};
 use namada_sdk::tx::new_tx_event;
 use namada_sdk::{ibc, proof_of_stake};
 use namada_vote_ext::ethereum_events::MultiSignedEthEvent;
@@ -530,6 +530,7 @@ where
             .tx_event
             .extend(GasUsed(scaled_gas))
             .extend(Info("Check batch for result.".to_string()))
+            .extend(TriggeredVps(tx_result.triggered_vps().into()))
             .extend(Batch(&tx_result.to_result_string()));
     }
 
@@ -587,6 +588,7 @@ where
 
         tx_logs
             .tx_event
+            .extend(TriggeredVps(tx_result.triggered_vps().into()))
             .extend(Batch(&tx_result.to_result_string()));
     }
 
@@ -5777,6 +5779,82 @@ mod test_finalize_block {
         assert_eq!(u64::from(cmd.min_confirmations), 42);
     }
 
+    /// Test that the applied tx event records the VPs triggered by the inner
+    /// txs of a batch and their verdicts.
+    #[test]
+    fn test_triggered_vps_event() {
+        let (mut shell, _broadcaster, _, _) = setup();
+        let sk = wallet::defaults::albert_keypair();
+        let owner = Address::from(&sk.ref_to());
+
+        let mut batch =
+            Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                Fee {
+                    amount_per_gas_unit: DenominatedAmount::native(1.into()),
+                    token: shell.state.in_mem().native_token.clone(),
+                },
+                sk.ref_to(),
+                WRAPPER_GAS_LIMIT.into(),
+            ))));
+        batch.header.chain_id = shell.chain_id.clone();
+
+        // A write to the signer's own account, accepted by its VP
+        let data = TxWriteData {
+            key: Key::from(owner.to_db_key())
+                .push(&"test".to_string())
+                .unwrap(),
+            value: STORAGE_VALUE.serialize_to_vec(),
+        };
+        batch.set_data(Data::new(data.serialize_to_vec()));
+        batch.set_code(Code::new(
+            TestWasms::TxWriteStorageKey.read_bytes(),
+            None,
+        ));
+
+        // A write to a protocol parameter, rejected by the parameters VP
+        batch.push_default_inner_tx();
+        let data = TxWriteData {
+            key: parameters::storage::get_max_block_gas_key(),
+            value: 1_u64.serialize_to_vec(),
+        };
+        batch.set_data(Data::new(data.serialize_to_vec()));
+        batch.set_code(Code::new(
+            TestWasms::TxWriteStorageKey.read_bytes(),
+            None,
+        ));
+
+        batch.add_section(Section::Authorization(Authorization::new(
+            vec![batch.raw_header_hash()],
+            [(0, sk.clone())].into_iter().collect(),
+            None,
+        )));
+        batch.add_section(Section::Authorization(Authorization::new(
+            batch.sechashes(),
+            [(0, sk.clone())].into_iter().collect(),
+            None,
+        )));
+        let processed_tx = ProcessedTx {
+            tx: batch.to_bytes().into(),
+            result: TxResult {
+                code: ResultCode::Ok.into(),
+                info: "".into(),
+            },
+        };
+
+        let event = &shell
+            .finalize_block(FinalizeBlock {
+                txs: vec![processed_tx],
+                ..Default::default()
+            })
+            .expect("Test failed");
+
+        assert_eq!(*event[0].kind(), APPLIED_TX);
+        let triggered_vps =
+            event[0].read_attribute::<TriggeredVps>().unwrap().0;
+        assert!(triggered_vps.contains(&(owner, true)));
+        assert!(triggered_vps.contains(&(address::PARAMETERS, false)));
+    }
+
     // Test a successful tx batch containing three valid transactions
     #[test]
     fn test_successful_batch() {
@@ -221,6 +221,7 @@ where
         let parameters = genesis.get_chain_parameters(&self.wasm_dir);
         self.store_wasms(&parameters)?;
         parameters::init_storage(&parameters, &mut self.state).unwrap();
+        parameters::write_genesis_time(&mut self.state, genesis_time).unwrap();
 
         // Initialize governance parameters
         let gov_params = genesis.get_gov_params();
@@ -462,6 +463,17 @@ where
             }
             // associate a token with its denomination.
             write_denom(&mut self.state, address, *denom).unwrap();
+            // the genesis alias of a token serves as its name and symbol
+            namada_sdk::token::write_metadata(
+                &mut self.state,
+                address,
+                &namada_sdk::token::TokenMetadata {
+                    name: alias.to_string(),
+                    symbol: alias.to_string(),
+                    decimals: *denom,
+                },
+            )
+            .unwrap();
             namada_sdk::token::write_params(
                 masp_params,
                 &mut self.state,
@@ -41,7 +41,7 @@ use namada_sdk::eth_bridge::protocol::validation::ethereum_events::validate_eth_
 use namada_sdk::eth_bridge::protocol::validation::validator_set_update::validate_valset_upd_vext;
 use namada_sdk::eth_bridge::{EthBridgeQueries, EthereumOracleConfig};
 use namada_sdk::ethereum_events::EthereumEvent;
-use namada_sdk::events::log::EventLog;
+use namada_sdk::events::log::{EventLog, Params as EventLogParams};
 use namada_sdk::gas::{Gas, TxGasMeter};
 use namada_sdk::hash::Hash;
 use namada_sdk::key::*;
@@ -498,6 +498,12 @@ where
         let mode = config.shell.tendermint_mode;
         let storage_read_past_height_limit =
             config.shell.storage_read_past_height_limit;
+        let event_log_retained_heights =
+            config.shell.event_log_retained_heights;
+        let warm_up_wasm_compilation_cache = config
+            .shell
+            .warm_up_wasm_compilation_cache
+            .unwrap_or_default();
         if !Path::new(&base_dir).is_dir() {
             std::fs::create_dir(&base_dir)
                 .expect("Creating directory for Namada should not fail");
@@ -527,7 +533,7 @@ where
         };
 
         // load last state from storage
-        let state = FullAccessState::open(
+        let mut state = FullAccessState::open(
             db_path,
             db_cache,
             chain_id.clone(),
@@ -535,6 +541,9 @@ where
             config.shell.storage_read_past_height_limit,
             is_key_diff_storable,
         );
+        if config.shell.record_read_stats.unwrap_or_default() {
+            state.write_log_mut().enable_read_stats();
+        }
         let vp_wasm_cache_dir =
             base_dir.join(chain_id.as_str()).join("vp_wasm_cache");
         let tx_wasm_cache_dir =
@@ -678,16 +687,56 @@ where
                 ),
             ),
             storage_read_past_height_limit,
-            // TODO(namada#3237): config event log params
-            event_log: EventLog::default(),
+            // TODO(namada#3237): config the other event log params
+            event_log: EventLog::new(EventLogParams {
+                retained_heights: event_log_retained_heights,
+                ..Default::default()
+            }),
             scheduled_migration,
             blocks_between_snapshots: config.shell.blocks_between_snapshots,
             syncing: None,
         };
         shell.update_eth_oracle(&Default::default());
+        if warm_up_wasm_compilation_cache {
+            shell.warm_up_wasm_compilation_cache();
+        }
         shell
     }
 
+    /// Compile the VP and tx WASMs found in the wasm directory into the
+    /// compilation caches, to avoid the compilation latency on their first
+    /// use. Only the WASMs whose code is stored on chain and allowlisted are
+    /// compiled, to not evict the used modules from the bounded caches.
+    fn warm_up_wasm_compilation_cache(&mut self) {
+        let Ok(params) = parameters::read(&self.state) else {
+            tracing::info!(
+                "Skipping the WASM compilation caches warm-up before the \
+                 chain is initialized"
+            );
+            return;
+        };
+        tracing::info!(
+            "Warming up the WASM compilation caches from {}",
+            self.wasm_dir.to_string_lossy()
+        );
+        let state = &self.state;
+        let is_on_chain = |allowlist: &[String], code_hash: &Hash| {
+            (allowlist.is_empty()
+                || allowlist.contains(&code_hash.to_string().to_lowercase()))
+                && state
+                    .has_key(&Key::wasm_code(code_hash))
+                    .unwrap_or_default()
+        };
+        self.vp_wasm_cache
+            .warm_up(&self.wasm_dir, "vp_", |code_hash| {
+                is_on_chain(&params.vp_allowlist, code_hash)
+            });
+        self.tx_wasm_cache
+            .warm_up(&self.wasm_dir, "tx_", |code_hash| {
+                is_on_chain(&params.tx_allowlist, code_hash)
+            });
+    }
+
     /// Return a reference to the [`EventLog`].
     #[inline]
     pub fn event_log(&self) -> &EventLog {
@@ -818,8 +867,9 @@ where
             _ => None,
         };
 
-        self.state
-            .commit_block()
+        let commit_stats = self
+            .state
+            .commit_block_with_stats()
             .expect("Encountered a storage error while committing a block");
 
         if let Some(migration) = migration {
@@ -829,11 +879,25 @@ where
                 .expect("Must update merkle tree after migration");
         }
 
+        // only prune events of heights that have already been committed
+        self.event_log.prune_to_retention(height_to_commit);
+
         let merkle_root = self.state.in_mem().merkle_root();
 
         tracing::info!(
             "Committed block hash: {merkle_root}, height: {height_to_commit}",
         );
+        tracing::debug!(
+            net_bytes_delta = commit_stats.net_bytes_delta,
+            "Storage size changed by the committed block",
+        );
+        if let Some(read_stats) = commit_stats.read_stats {
+            tracing::debug!(
+                log_hits = read_stats.log_hits,
+                storage_hits = read_stats.storage_hits,
+                "Storage reads of the committed block",
+            );
+        }
 
         self.broadcast_queued_txs();
         let take_snapshot = self.check_snapshot_required();
@@ -1106,13 +1170,9 @@ where
 
         // Tx chain id
         #[cfg(not(fuzzing))]
-        if tx.header.chain_id != self.chain_id {
+        if let Err(err) = tx.validate_chain_id(&self.chain_id) {
             response.code = ResultCode::InvalidChainId.into();
-            response.log = format!(
-                "{INVALID_MSG}: Tx carries a wrong chain id: expected {}, \
-                 found {}",
-                self.chain_id, tx.header.chain_id
-            );
+            response.log = format!("{INVALID_MSG}: {err}");
             return response;
         }
 
@@ -1134,7 +1194,7 @@ where
                 .get_last_block_timestamp()
                 .expect("Failed to retrieve last block timestamp");
 
-            if last_block_timestamp > exp {
+            if tx.is_expired(last_block_timestamp) {
                 response.code = ResultCode::ExpiredTx.into();
                 response.log = format!(
                     "{INVALID_MSG}: Tx expired at {exp:#?}, last committed \
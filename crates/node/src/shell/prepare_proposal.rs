@@ -295,12 +295,8 @@ where
     // If tx doesn't have an expiration it is valid. If time cannot be
     // retrieved from block default to last block datetime which has
     // already been checked by mempool_validate, so it's valid
-    if let (Some(block_time), Some(exp)) =
-        (block_time.as_ref(), &tx.header().expiration)
-    {
-        if block_time > exp {
-            return Err(());
-        }
+    if block_time.is_some_and(|block_time| tx.is_expired(block_time)) {
+        return Err(());
     }
 
     // Check tx gas limit for tx size
@@ -249,10 +249,9 @@ where
                 })
             },
             |tx| {
-                let tx_chain_id = tx.header.chain_id.clone();
                 let tx_expiration = tx.header.expiration;
                 match tx.validate_tx() {
-                    Ok(_) => Ok((tx_chain_id, tx_expiration, tx)),
+                    Ok(_) => Ok((tx_expiration, tx)),
                     // This occurs if the wrapper / protocol tx signature is
                     // invalid
                     Err(err) => Err(TxResult {
@@ -262,7 +261,7 @@ where
                 }
             },
         );
-        let (tx_chain_id, tx_expiration, tx) = match maybe_tx {
+        let (tx_expiration, tx) = match maybe_tx {
             Ok(tx) => tx,
             Err(tx_result) => return tx_result,
         };
@@ -277,28 +276,22 @@ where
             },
             TxType::Protocol(protocol_tx) => {
                 // Tx chain id
-                if tx_chain_id != self.chain_id {
+                if let Err(err) = tx.validate_chain_id(&self.chain_id) {
                     return TxResult {
                         code: ResultCode::InvalidChainId.into(),
-                        info: format!(
-                            "Tx carries a wrong chain id: expected {}, found \
-                             {}",
-                            self.chain_id, tx_chain_id
-                        ),
+                        info: err.to_string(),
                     };
                 }
 
                 // Tx expiration
-                if let Some(exp) = tx_expiration {
-                    if block_time > exp {
-                        return TxResult {
-                            code: ResultCode::ExpiredTx.into(),
-                            info: format!(
-                                "Tx expired at {:#?}, block time: {:#?}",
-                                exp, block_time
-                            ),
-                        };
-                    }
+                if tx.is_expired(block_time) {
+                    return TxResult {
+                        code: ResultCode::ExpiredTx.into(),
+                        info: format!(
+                            "Tx expired at {:#?}, block time: {:#?}",
+                            tx_expiration, block_time
+                        ),
+                    };
                 }
 
                 match protocol_tx.tx {
@@ -467,14 +460,10 @@ where
                 };
 
                 // ChainId check
-                if tx_chain_id != self.chain_id {
+                if let Err(err) = tx.validate_chain_id(&self.chain_id) {
                     return TxResult {
                         code: ResultCode::InvalidChainId.into(),
-                        info: format!(
-                            "Tx carries a wrong chain id: expected {}, found \
-                             {}",
-                            self.chain_id, tx_chain_id
-                        ),
+                        info: err.to_string(),
                     };
                 }
 
@@ -490,16 +479,14 @@ where
                 }
 
                 // Tx expiration
-                if let Some(exp) = tx_expiration {
-                    if block_time > exp {
-                        return TxResult {
-                            code: ResultCode::ExpiredTx.into(),
-                            info: format!(
-                                "Tx expired at {:#?}, block time: {:#?}",
-                                exp, block_time
-                            ),
-                        };
-                    }
+                if tx.is_expired(block_time) {
+                    return TxResult {
+                        code: ResultCode::ExpiredTx.into(),
+                        info: format!(
+                            "Tx expired at {:#?}, block time: {:#?}",
+                            tx_expiration, block_time
+                        ),
+                    };
                 }
 
                 // Replay protection checks
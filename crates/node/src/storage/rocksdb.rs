@@ -1975,6 +1975,24 @@ impl<'iter> DBIter<'iter> for RocksDB {
         iter_subspace_prefix(self, prefix)
     }
 
+    fn iter_prefix_with_batch(
+        &'iter self,
+        prefix: Option<&Key>,
+        batch_size: usize,
+    ) -> PersistentPrefixIterator<'iter> {
+        let subspace_cf = self
+            .get_column_family(SUBSPACE_CF)
+            .expect("{SUBSPACE_CF} column family should exist");
+        let stripped_prefix = None;
+        iter_prefix_with_readahead(
+            self,
+            subspace_cf,
+            stripped_prefix,
+            prefix,
+            Some(batch_size),
+        )
+    }
+
     fn iter_pattern(
         &'iter self,
         prefix: Option<&Key>,
@@ -2084,6 +2102,18 @@ fn iter_prefix<'a>(
     cf: &'a ColumnFamily,
     stripped_prefix: Option<&Key>,
     prefix: Option<&Key>,
+) -> PersistentPrefixIterator<'a> {
+    iter_prefix_with_readahead(db, cf, stripped_prefix, prefix, None)
+}
+
+/// Like [`iter_prefix`], but with the given number of bytes to read ahead
+/// while iterating. With no `readahead_size`, the DB's default is used.
+fn iter_prefix_with_readahead<'a>(
+    db: &'a RocksDB,
+    cf: &'a ColumnFamily,
+    stripped_prefix: Option<&Key>,
+    prefix: Option<&Key>,
+    readahead_size: Option<usize>,
 ) -> PersistentPrefixIterator<'a> {
     let stripped_prefix = match stripped_prefix {
         Some(p) if !p.is_empty() => format!("{p}/"),
@@ -2095,7 +2125,10 @@ fn iter_prefix<'a>(
         }
         _ => stripped_prefix.clone(),
     };
-    let read_opts = make_iter_read_opts(Some(prefix.clone()));
+    let mut read_opts = make_iter_read_opts(Some(prefix.clone()));
+    if let Some(readahead_size) = readahead_size {
+        read_opts.set_readahead_size(readahead_size);
+    }
     let iter = db.inner.iterator_cf_opt(
         cf,
         read_opts,
@@ -2470,6 +2503,41 @@ mod test {
         itertools::assert_equal(all_keys, itered_keys);
     }
 
+    #[test]
+    fn test_prefix_iter_with_batch() {
+        let dir = tempdir().unwrap();
+        let db = RocksDB::open(dir.path(), None);
+
+        let prefix = Key::parse("prefix").unwrap();
+        let keys: Vec<Key> = (0..100)
+            .map(|i| prefix.push(&format!("{i:03}")).unwrap())
+            .collect();
+
+        // Write the keys
+        let mut batch = RocksDB::batch();
+        let height = BlockHeight(1);
+        for key in &keys {
+            db.batch_write_subspace_val(
+                &mut batch, height, key, [0_u8; 64], true,
+            )
+            .unwrap();
+        }
+        db.exec_batch(batch).unwrap();
+
+        let expected: Vec<(String, Vec<u8>)> = db
+            .iter_prefix(Some(&prefix))
+            .map(|(key, val, _)| (key, val))
+            .collect();
+        assert_eq!(expected.len(), keys.len());
+        for batch_size in [0, 1, 64, 4096, 2 << 20] {
+            let itered: Vec<(String, Vec<u8>)> = db
+                .iter_prefix_with_batch(Some(&prefix), batch_size)
+                .map(|(key, val, _)| (key, val))
+                .collect();
+            assert_eq!(expected, itered);
+        }
+    }
+
     #[test]
     fn test_rollback() {
         for persist_diffs in [true, false] {
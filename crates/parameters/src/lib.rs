@@ -25,9 +25,9 @@ use std::marker::PhantomData;
 
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
-use namada_core::chain::BlockHeight;
+use namada_core::chain::{BlockHeight, Epoch};
 pub use namada_core::parameters::ProposalBytes;
-use namada_core::time::DurationSecs;
+use namada_core::time::{DateTimeUtc, DurationSecs};
 use namada_core::{hints, token};
 use namada_state::{Error, Key, ResultExt, StorageRead, StorageWrite};
 pub use namada_systems::parameters::*;
@@ -277,6 +277,53 @@ where
     storage.write(&key, implicit_vp)
 }
 
+/// Schedule the given parameters to take effect from the `epoch`, replacing
+/// any previously scheduled change.
+pub fn write_pending_parameters<S>(
+    storage: &mut S,
+    epoch: Epoch,
+    parameters: &Parameters,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage::get_pending_parameters_key();
+    storage.write(&key, (epoch, parameters))
+}
+
+/// Read the parameters scheduled to take effect at a future epoch together
+/// with their activation epoch, if any.
+pub fn read_pending_parameters<S>(
+    storage: &S,
+) -> Result<Option<(Epoch, Parameters)>>
+where
+    S: StorageRead,
+{
+    let key = storage::get_pending_parameters_key();
+    storage.read(&key)
+}
+
+/// Write the genesis time of the chain.
+pub fn write_genesis_time<S>(
+    storage: &mut S,
+    genesis_time: DateTimeUtc,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage::get_genesis_time_key();
+    storage.write(&key, genesis_time)
+}
+
+/// Read the genesis time of the chain, if it was stored at genesis.
+pub fn read_genesis_time<S>(storage: &S) -> Result<Option<DateTimeUtc>>
+where
+    S: StorageRead,
+{
+    let key = storage::get_genesis_time_key();
+    storage.read(&key)
+}
+
 /// Read the epochs per year parameter from store
 pub fn read_epochs_per_year_parameter<S>(storage: &S) -> Result<u64>
 where
@@ -612,12 +659,31 @@ where
 
 #[cfg(test)]
 mod tests {
+    use namada_core::borsh::BorshSerializeExt;
     use namada_core::chain::BlockHeader;
     use namada_core::time::DateTimeUtc;
-    use namada_state::testing::TestStorage;
+    use namada_state::testing::{TestState, TestStorage};
 
     use super::*;
 
+    #[test]
+    fn test_pending_parameters() {
+        let mut storage = TestStorage::default();
+        assert_eq!(read_pending_parameters(&storage).unwrap(), None);
+
+        init_test_storage(&mut storage).unwrap();
+        let mut parameters = read(&storage).unwrap();
+        parameters.max_block_gas = 200;
+        write_pending_parameters(&mut storage, Epoch(5), &parameters).unwrap();
+
+        assert_eq!(
+            read_pending_parameters(&storage).unwrap(),
+            Some((Epoch(5), parameters))
+        );
+        // The active parameters are unaffected
+        assert_eq!(read(&storage).unwrap().max_block_gas, 100);
+    }
+
     #[test]
     fn test_estimate_max_block_time_from_parameters_lower_bound() {
         let mut storage = TestStorage::default();
@@ -819,4 +885,60 @@ mod tests {
 
         assert_eq!(max_block_time, Some(BLOCK_TIME_ESTIMATE_UPPER_BOUND));
     }
+
+    #[test]
+    fn test_read_parameters() {
+        let mut storage = TestStorage::default();
+        init_test_storage(&mut storage).unwrap();
+
+        let params = read(&storage).unwrap();
+        assert_eq!(params.max_block_gas, 100);
+        assert_eq!(params.epochs_per_year, 365);
+        assert_eq!(params.masp_epoch_multiplier, 2);
+
+        assert_eq!(get_max_block_gas(&storage).unwrap(), 100);
+        assert_eq!(read_epochs_per_year(&storage).unwrap(), 365);
+        assert_eq!(
+            read_epoch_duration_parameter(&storage).unwrap(),
+            params.epoch_duration
+        );
+    }
+
+    #[test]
+    fn test_read_parameters_from_write_log() {
+        let mut state = TestState::default();
+        init_test_storage(&mut state).unwrap();
+        state.commit_block().unwrap();
+
+        // Modify the parameters in the current tx
+        let _ = state
+            .write_log_mut()
+            .write(&storage::get_max_block_gas_key(), 200u64.serialize_to_vec())
+            .unwrap();
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 10,
+            min_duration: DurationSecs(60),
+        };
+        let _ = state
+            .write_log_mut()
+            .write(
+                &storage::get_epoch_duration_storage_key(),
+                epoch_duration.serialize_to_vec(),
+            )
+            .unwrap();
+
+        assert_eq!(get_max_block_gas(&state).unwrap(), 200);
+        assert_eq!(
+            read_epoch_duration_parameter(&state).unwrap(),
+            epoch_duration
+        );
+        let params = read(&state).unwrap();
+        assert_eq!(params.max_block_gas, 200);
+        assert_eq!(params.epoch_duration, epoch_duration);
+
+        // Dropping the tx restores the committed values
+        state.write_log_mut().drop_tx();
+        assert_eq!(get_max_block_gas(&state).unwrap(), 100);
+        assert_eq!(read(&state).unwrap().max_block_gas, 100);
+    }
 }
@@ -41,6 +41,13 @@ struct Keys {
     masp_fee_payment_gas_limit: &'static str,
     gas_scale: &'static str,
     native_token_transferable: &'static str,
+    max_account_writes_per_block: &'static str,
+    max_tx_changed_keys: &'static str,
+    /// Sub-key for storing parameters scheduled to take effect at a future
+    /// epoch, together with their activation epoch.
+    pending_parameters: &'static str,
+    /// Sub-key for storing the time of the genesis of the chain.
+    genesis_time: &'static str,
 }
 
 /// Returns if the key is a parameter key.
@@ -121,6 +128,17 @@ pub fn get_gas_scale_key() -> Key {
     get_gas_scale_key_at_addr(ADDRESS)
 }
 
+/// Storage key used for the genesis time of the chain.
+pub fn get_genesis_time_key() -> Key {
+    get_genesis_time_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the parameters scheduled to take effect at a future
+/// epoch.
+pub fn get_pending_parameters_key() -> Key {
+    get_pending_parameters_key_at_addr(ADDRESS)
+}
+
 /// Storage key used for implicit VP parameter.
 pub fn get_implicit_vp_key() -> Key {
     get_implicit_vp_key_at_addr(ADDRESS)
@@ -181,6 +199,36 @@ pub fn get_native_token_transferable_key() -> Key {
     get_native_token_transferable_key_at_addr(ADDRESS)
 }
 
+/// Storage key used for the maximum number of storage modifications allowed
+/// per account per block
+pub fn get_max_account_writes_per_block_key() -> Key {
+    get_max_account_writes_per_block_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the optional `max_account_writes_per_block`
+/// protocol parameter from storage. When it's not set, writes are not
+/// rate-limited.
+pub fn read_max_account_writes_per_block(
+    storage: &impl StorageRead,
+) -> Result<Option<u64>> {
+    storage.read(&get_max_account_writes_per_block_key())
+}
+
+/// Storage key used for the maximum number of distinct storage keys that a
+/// single tx may change
+pub fn get_max_tx_changed_keys_key() -> Key {
+    get_max_tx_changed_keys_key_at_addr(ADDRESS)
+}
+
+/// Helper function to retrieve the optional `max_tx_changed_keys` protocol
+/// parameter from storage. When it's not set, the number of keys changed by a
+/// tx is not limited.
+pub fn read_max_tx_changed_keys(
+    storage: &impl StorageRead,
+) -> Result<Option<u64>> {
+    storage.read(&get_max_tx_changed_keys_key())
+}
+
 /// Helper function to retrieve the `is_native_token_transferable` protocol
 /// parameter from storage
 pub fn is_native_token_transferable(
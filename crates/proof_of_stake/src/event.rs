@@ -1,10 +1,12 @@
 //! Proof of Stake events.
 
 use namada_core::address::Address;
+use namada_core::chain::Epoch;
 use namada_core::token;
 use namada_core::uint::Uint;
-use namada_events::extend::{ComposeEvent, EventAttributeEntry};
+use namada_events::extend::{ComposeEvent, EventAttributeEntry, EventValue};
 use namada_events::{Event, EventLevel, EventToEmit};
+use serde::{Deserialize, Serialize};
 
 pub mod types {
     //! Proof of Stake event types.
@@ -15,6 +17,10 @@ pub mod types {
 
     /// Slash event.
     pub const SLASH: EventType = event_type!(PosEvent, "slash");
+
+    /// Consensus validator set change event.
+    pub const VALIDATOR_SET_CHANGE: EventType =
+        event_type!(PosEvent, "validator-set-change");
 }
 
 /// Proof of Stake event.
@@ -27,6 +33,29 @@ pub enum PosEvent {
         /// Amount of tokens that have been slashed.
         amount: token::Amount,
     },
+    /// Change of the consensus validator set at the start of an epoch.
+    ValidatorSetChange {
+        /// The epoch from which the new consensus validator set is active.
+        epoch: Epoch,
+        /// Validators that joined the consensus validator set.
+        added: Vec<Address>,
+        /// Validators that left the consensus validator set.
+        removed: Vec<Address>,
+        /// Stake changes of the validators that remained in the consensus
+        /// validator set.
+        power_changes: Vec<ValidatorPowerChange>,
+    },
+}
+
+/// The change of the stake of a consensus validator between two epochs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorPowerChange {
+    /// The address of the validator.
+    pub validator: Address,
+    /// The stake of the validator in the previous epoch.
+    pub prev_stake: token::Amount,
+    /// The stake of the validator in the new epoch.
+    pub new_stake: token::Amount,
 }
 
 impl EventToEmit for PosEvent {
@@ -42,6 +71,17 @@ impl From<PosEvent> for Event {
                     .with(SlashedAmount(&amount.into()))
                     .into()
             }
+            PosEvent::ValidatorSetChange {
+                epoch,
+                added,
+                removed,
+                power_changes,
+            } => Event::new(types::VALIDATOR_SET_CHANGE, EventLevel::Block)
+                .with(ValidatorSetEpoch(epoch))
+                .with(AddedValidators(added.into()))
+                .with(RemovedValidators(removed.into()))
+                .with(ValidatorPowerChanges(power_changes.into()))
+                .into(),
         }
     }
 }
@@ -73,3 +113,59 @@ impl<'amt> EventAttributeEntry<'amt> for SlashedAmount<'amt> {
         self.0
     }
 }
+
+/// Extend an [`Event`] with the epoch of a validator set change.
+pub struct ValidatorSetEpoch(pub Epoch);
+
+impl EventAttributeEntry<'static> for ValidatorSetEpoch {
+    type Value = Epoch;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "validator-set-epoch";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the validators added to a validator set.
+pub struct AddedValidators(pub EventValue<Vec<Address>>);
+
+impl EventAttributeEntry<'static> for AddedValidators {
+    type Value = EventValue<Vec<Address>>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "added-validators";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the validators removed from a validator set.
+pub struct RemovedValidators(pub EventValue<Vec<Address>>);
+
+impl EventAttributeEntry<'static> for RemovedValidators {
+    type Value = EventValue<Vec<Address>>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "removed-validators";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the stake changes of validators.
+pub struct ValidatorPowerChanges(pub EventValue<Vec<ValidatorPowerChange>>);
+
+impl EventAttributeEntry<'static> for ValidatorPowerChanges {
+    type Value = EventValue<Vec<ValidatorPowerChange>>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "validator-power-changes";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
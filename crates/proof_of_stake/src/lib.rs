@@ -198,11 +198,7 @@ where
     where
         Gov: governance::Read<S>,
     {
-        let bond_id = BondId {
-            source: delegator.clone(),
-            validator: validator.clone(),
-        };
-        bond_amount::<S, Gov>(storage, &bond_id, epoch)
+        effective_bond::<S, Gov>(storage, validator, delegator, epoch)
     }
 }
 
@@ -270,7 +266,9 @@ where
     Ok(())
 }
 
-/// Check if the provided address is a validator address
+/// Check if the provided address is a validator address. Any address that
+/// has ever become a validator counts, regardless of its current state, so
+/// inactive and jailed validators are validators too.
 pub fn is_validator<S>(storage: &S, address: &Address) -> Result<bool>
 where
     S: StorageRead,
@@ -1883,59 +1881,97 @@ where
 
     if !amounts.is_empty() {
         let slashes = find_validator_slashes(storage, &bond_id.validator)?;
-        let redelegated_bonded =
-            delegator_redelegated_bonds_handle(&bond_id.source)
-                .at(&bond_id.validator);
 
         // Apply slashes
         for (&start, amount) in amounts.iter_mut() {
-            let list_slashes = slashes
-                .iter()
-                .filter(|slash| {
-                    let processing_epoch = slash
-                        .epoch
-                        .unchecked_add(params.slash_processing_epoch_offset());
-                    // Only use slashes that were processed before or at the
-                    // epoch associated with the bond amount. This assumes
-                    // that slashes are applied before inflation.
-                    processing_epoch <= epoch && start <= slash.epoch
-                })
-                .cloned()
-                .collect::<Vec<_>>();
+            *amount = slash_bond_at_epoch(
+                storage, &params, bond_id, &slashes, start, *amount, epoch,
+            )?;
+        }
+    }
 
-            let slash_epoch_filter = |e: Epoch| {
-                e.unchecked_add(params.slash_processing_epoch_offset()) <= epoch
-            };
+    token::Amount::sum(amounts.values().copied())
+        .ok_or_err_msg("token amount overflow")
+}
 
-            let redelegated_bonds =
-                redelegated_bonded.at(&start).collect_map(storage)?;
+/// Get the effective bond amount of the `delegator` to the `validator` at the
+/// given `epoch`. This is the raw bond amount with all the slashes that were
+/// processed before or at the `epoch` applied to it. For future epochs, the
+/// value is subject to change.
+pub fn effective_bond<S, Gov>(
+    storage: &S,
+    validator: &Address,
+    delegator: &Address,
+    epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    bond_amount::<S, Gov>(storage, &bond_id, epoch)
+}
 
-            let result_fold = fold_and_slash_redelegated_bonds(
-                storage,
-                &params,
-                &redelegated_bonds,
-                start,
-                &list_slashes,
-                slash_epoch_filter,
-            )?;
+/// Apply the given `slashes` of the bond's validator, and the slashes of the
+/// validators that the bond was redelegated from, to the `amount` bonded at
+/// the `start` epoch. Only the slashes that were processed before or at the
+/// `epoch` are applied.
+fn slash_bond_at_epoch<S>(
+    storage: &S,
+    params: &PosParams,
+    bond_id: &BondId,
+    slashes: &[Slash],
+    start: Epoch,
+    amount: token::Amount,
+    epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let list_slashes = slashes
+        .iter()
+        .filter(|slash| {
+            let processing_epoch = slash
+                .epoch
+                .unchecked_add(params.slash_processing_epoch_offset());
+            // Only use slashes that were processed before or at the epoch
+            // associated with the bond amount. This assumes that slashes are
+            // applied before inflation.
+            processing_epoch <= epoch && start <= slash.epoch
+        })
+        .cloned()
+        .collect::<Vec<_>>();
 
-            let total_not_redelegated =
-                checked!(amount - result_fold.total_redelegated)?;
+    let slash_epoch_filter = |e: Epoch| {
+        e.unchecked_add(params.slash_processing_epoch_offset()) <= epoch
+    };
 
-            let after_not_redelegated = apply_list_slashes(
-                &params,
-                &list_slashes,
-                total_not_redelegated,
-            )?;
+    let redelegated_bonds = delegator_redelegated_bonds_handle(&bond_id.source)
+        .at(&bond_id.validator)
+        .at(&start)
+        .collect_map(storage)?;
 
-            *amount = checked!(
-                after_not_redelegated + result_fold.total_after_slashing
-            )?;
-        }
-    }
+    let result_fold = fold_and_slash_redelegated_bonds(
+        storage,
+        params,
+        &redelegated_bonds,
+        start,
+        &list_slashes,
+        slash_epoch_filter,
+    )?;
 
-    token::Amount::sum(amounts.values().copied())
-        .ok_or_err_msg("token amount overflow")
+    let total_not_redelegated =
+        checked!(amount - result_fold.total_redelegated)?;
+
+    let after_not_redelegated =
+        apply_list_slashes(params, &list_slashes, total_not_redelegated)?;
+
+    Ok(checked!(
+        after_not_redelegated + result_fold.total_after_slashing
+    )?)
 }
 
 /// Get bond amounts within the `claim_start..=claim_end` epoch range for
@@ -1986,55 +2022,12 @@ where
 
     if !amounts.is_empty() {
         let slashes = find_validator_slashes(storage, &bond_id.validator)?;
-        let redelegated_bonded =
-            delegator_redelegated_bonds_handle(&bond_id.source)
-                .at(&bond_id.validator);
 
         // Apply slashes
         for (&ep, amounts) in amounts.iter_mut() {
             for (&start, amount) in amounts.iter_mut() {
-                let list_slashes = slashes
-                    .iter()
-                    .filter(|slash| {
-                        let processing_epoch = slash.epoch.unchecked_add(
-                            params.slash_processing_epoch_offset(),
-                        );
-                        // Only use slashes that were processed before or at the
-                        // epoch associated with the bond amount. This assumes
-                        // that slashes are applied before inflation.
-                        processing_epoch <= ep && start <= slash.epoch
-                    })
-                    .cloned()
-                    .collect::<Vec<_>>();
-
-                let slash_epoch_filter = |e: Epoch| {
-                    e.unchecked_add(params.slash_processing_epoch_offset())
-                        <= ep
-                };
-
-                let redelegated_bonds =
-                    redelegated_bonded.at(&start).collect_map(storage)?;
-
-                let result_fold = fold_and_slash_redelegated_bonds(
-                    storage,
-                    &params,
-                    &redelegated_bonds,
-                    start,
-                    &list_slashes,
-                    slash_epoch_filter,
-                )?;
-
-                let total_not_redelegated =
-                    checked!(amount - result_fold.total_redelegated)?;
-
-                let after_not_redelegated = apply_list_slashes(
-                    &params,
-                    &list_slashes,
-                    total_not_redelegated,
-                )?;
-
-                *amount = checked!(
-                    after_not_redelegated + result_fold.total_after_slashing
+                *amount = slash_bond_at_epoch(
+                    storage, &params, bond_id, &slashes, start, *amount, ep,
                 )?;
             }
         }
@@ -3005,6 +2998,14 @@ where
             storage,
             current_epoch,
         )?;
+
+        // Notify about changes to the consensus validator set that became
+        // active in the new epoch
+        validator_set_update::emit_validator_set_change(
+            storage,
+            events,
+            current_epoch,
+        )?;
     }
 
     // Invariant: Has to be applied before `record_slashes_from_evidence`
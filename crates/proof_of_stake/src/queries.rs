@@ -17,7 +17,7 @@ use crate::slashing::{find_validator_slashes, get_slashed_amount};
 use crate::storage::{
     bond_handle, delegation_targets_handle,
     read_consensus_validator_set_addresses, read_pos_params, unbond_handle,
-    validator_eth_hot_key_handle,
+    validator_commission_rate_handle, validator_eth_hot_key_handle,
 };
 use crate::types::{
     BondDetails, BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails,
@@ -28,6 +28,22 @@ use crate::{
     storage_key,
 };
 
+/// Find the commission rates of a validator still kept in storage, keyed by
+/// the epoch from which each rate is in effect. The map is empty for an
+/// address that has never been a validator.
+pub fn find_validator_commission_rates<S>(
+    storage: &S,
+    validator: &Address,
+) -> Result<BTreeMap<Epoch, Dec>>
+where
+    S: StorageRead,
+{
+    validator_commission_rate_handle(validator)
+        .get_data_handler()
+        .iter(storage)?
+        .collect()
+}
+
 /// Find all validators to which a given bond `owner` (or source) has a
 /// delegation
 pub fn find_delegation_validators<S>(
@@ -232,6 +232,21 @@ where
     crate::bond_amount::<S, GovStore<S>>(storage, bond_id, epoch)
 }
 
+/// DI indirection
+pub fn effective_bond<S>(
+    storage: &S,
+    validator: &Address,
+    delegator: &Address,
+    epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    crate::effective_bond::<S, GovStore<S>>(
+        storage, validator, delegator, epoch,
+    )
+}
+
 /// DI indirection
 pub fn deactivate_validator<S>(
     storage: &mut S,
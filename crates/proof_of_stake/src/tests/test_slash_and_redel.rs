@@ -38,9 +38,9 @@ use crate::tests::helpers::{
     test_slashes_with_unbonding_params,
 };
 use crate::tests::{
-    bond_amount, bond_tokens, bonds_and_unbonds, process_slashes,
-    redelegate_tokens, slash, test_init_genesis, unbond_tokens,
-    withdraw_tokens,
+    bond_amount, bond_tokens, bonds_and_unbonds, effective_bond,
+    process_slashes, redelegate_tokens, slash, test_init_genesis,
+    unbond_tokens, withdraw_tokens,
 };
 use crate::types::{BondId, GenesisValidator, Slash, SlashType};
 use crate::{
@@ -1668,6 +1668,119 @@ fn test_slashed_bond_amount_aux(validators: Vec<GenesisValidator>) {
     assert!(diff <= 2.into());
 }
 
+#[test]
+fn test_effective_bond_with_slash() {
+    let mut storage = TestState::default();
+    let params = OwnedPosParams {
+        unbonding_len: 4,
+        validator_stake_threshold: token::Amount::zero(),
+        ..Default::default()
+    };
+
+    let validator = established_address_1();
+    let gen_validators = [GenesisValidator {
+        address: validator.clone(),
+        tokens: 100.into(),
+        consensus_key: keypair_1().ref_to(),
+        protocol_key: keypair_3().ref_to(),
+        eth_cold_key: keypair_3().ref_to(),
+        eth_hot_key: keypair_3().ref_to(),
+        commission_rate: Default::default(),
+        max_commission_rate_change: Default::default(),
+        metadata: Default::default(),
+    }];
+
+    // Genesis
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        gen_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    // Delegate to the validator
+    let staking_token = staking_token_address(&storage);
+    let delegator = address::testing::gen_implicit_address();
+    let amount = token::Amount::from(1_000);
+    credit_tokens(&mut storage, &staking_token, &delegator, amount).unwrap();
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    let bond_start = current_epoch + params.pipeline_len;
+
+    // Slash the validator after the bond has started contributing to its stake
+    let slash_epoch = bond_start.next();
+    validator_slashes_handle(&validator)
+        .push(
+            &mut storage,
+            Slash {
+                block_height: 0,
+                epoch: slash_epoch,
+                r#type: SlashType::DuplicateVote,
+                rate: Dec::from_str("0.1").unwrap(),
+            },
+        )
+        .unwrap();
+    let processing_epoch = slash_epoch + params.slash_processing_epoch_offset();
+
+    // Before the bond starts, there's nothing
+    assert_eq!(
+        effective_bond(&storage, &validator, &delegator, current_epoch)
+            .unwrap(),
+        token::Amount::zero()
+    );
+    // The slash is not applied until it's processed
+    assert_eq!(
+        effective_bond(&storage, &validator, &delegator, bond_start).unwrap(),
+        amount
+    );
+    assert_eq!(
+        effective_bond(
+            &storage,
+            &validator,
+            &delegator,
+            processing_epoch.prev().unwrap()
+        )
+        .unwrap(),
+        amount
+    );
+    // Once processed, the slash reduces the effective bond
+    let exp_amount = token::Amount::from(900);
+    assert_eq!(
+        effective_bond(&storage, &validator, &delegator, processing_epoch)
+            .unwrap(),
+        exp_amount
+    );
+    assert_eq!(
+        effective_bond(
+            &storage,
+            &validator,
+            &delegator,
+            processing_epoch.next()
+        )
+        .unwrap(),
+        exp_amount
+    );
+    // The effective bond is the slashed bond amount
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    assert_eq!(
+        bond_amount(&storage, &bond_id, processing_epoch).unwrap(),
+        exp_amount
+    );
+}
+
 #[test]
 fn test_one_slash_per_block_height() {
     let mut storage = TestState::default();
@@ -2,7 +2,9 @@
 
 use std::cmp::min;
 
-use namada_core::address::testing::arb_established_address;
+use namada_core::address::testing::{
+    arb_established_address, established_address_2,
+};
 use namada_core::address::{self, Address, EstablishedAddressGen};
 use namada_core::chain::Epoch;
 use namada_core::dec::Dec;
@@ -11,6 +13,7 @@ use namada_core::key::testing::{
 };
 use namada_core::key::{self, RefTo, common};
 use namada_core::token;
+use namada_events::Event;
 use namada_state::testing::TestState;
 use namada_trans_token::credit_tokens;
 use proptest::prelude::*;
@@ -20,6 +23,10 @@ use proptest::test_runner::Config;
 use test_log::test;
 
 use crate::epoched::DEFAULT_NUM_PAST_EPOCHS;
+use crate::event::{
+    AddedValidators, RemovedValidators, ValidatorPowerChanges,
+    ValidatorSetEpoch, types as event_types,
+};
 use crate::storage::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, find_validator_by_raw_hash,
@@ -29,6 +36,7 @@ use crate::storage::{
     validator_addresses_handle, validator_consensus_key_handle,
     validator_set_positions_handle, write_validator_address_raw_hash,
 };
+use crate::test_utils::get_dummy_genesis_validator;
 use crate::tests::helpers::{
     advance_epoch, arb_genesis_validators, arb_params_and_genesis_validators,
     get_tendermint_set_updates,
@@ -43,7 +51,8 @@ use crate::types::{
     ValidatorSetUpdate, WeightedValidator, into_tm_voting_power,
 };
 use crate::validator_set_update::{
-    insert_validator_into_validator_set, update_validator_set,
+    emit_validator_set_change, insert_validator_into_validator_set,
+    update_validator_set,
 };
 use crate::{
     BecomeValidator, OwnedPosParams, is_validator, lazy_map,
@@ -1446,3 +1455,100 @@ fn test_purge_validator_information_aux(validators: Vec<GenesisValidator>) {
         assert!(!consensus_val_set.at(&ep).is_empty(&s).unwrap());
     }
 }
+
+/// Test that a validator set change event is emitted only at the epoch in
+/// which a new validator joins the consensus validator set.
+#[test]
+fn test_validator_set_change_event() {
+    let mut s = TestState::default();
+    let params = OwnedPosParams {
+        max_validator_slots: 2,
+        ..Default::default()
+    };
+
+    let genesis_validator = get_dummy_genesis_validator();
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        [genesis_validator].into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Nothing changes between the genesis epoch and the next one
+    current_epoch = advance_epoch(&mut s, &params);
+    let mut events: Vec<Event> = vec![];
+    emit_validator_set_change(&s, &mut events, current_epoch).unwrap();
+    assert!(events.is_empty());
+
+    // A new validator with some stake joins at the pipeline offset
+    let new_validator = established_address_2();
+    let consensus_key = common_sk_from_simple_seed(2).to_public();
+    let protocol_key = common_sk_from_simple_seed(3).to_public();
+    let eth_hot_key = key::common::PublicKey::Secp256k1(
+        key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+    );
+    let eth_cold_key = key::common::PublicKey::Secp256k1(
+        key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+    );
+    become_validator(
+        &mut s,
+        BecomeValidator {
+            params: &params,
+            address: &new_validator,
+            consensus_key: &consensus_key,
+            protocol_key: &protocol_key,
+            eth_cold_key: &eth_cold_key,
+            eth_hot_key: &eth_hot_key,
+            current_epoch,
+            commission_rate: Dec::new(5, 2).expect("Dec creation failed"),
+            max_commission_rate_change: Dec::new(5, 2)
+                .expect("Dec creation failed"),
+            metadata: Default::default(),
+            offset_opt: None,
+        },
+    )
+    .unwrap();
+    let staking_token = staking_token_address(&s);
+    let amount = token::Amount::native_whole(10);
+    credit_tokens(&mut s, &staking_token, &new_validator, amount).unwrap();
+    bond_tokens(&mut s, None, &new_validator, amount, current_epoch, None)
+        .unwrap();
+    let join_epoch = current_epoch + params.pipeline_len;
+
+    while current_epoch < join_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+        emit_validator_set_change(&s, &mut events, current_epoch).unwrap();
+        if current_epoch < join_epoch {
+            assert!(events.is_empty());
+        }
+    }
+
+    assert_eq!(events.len(), 1);
+    let event = events.pop().unwrap();
+    assert_eq!(*event.kind(), event_types::VALIDATOR_SET_CHANGE);
+    assert_eq!(
+        event.read_attribute::<ValidatorSetEpoch>().unwrap(),
+        join_epoch
+    );
+    assert_eq!(
+        event.read_attribute::<AddedValidators>().unwrap().0,
+        vec![new_validator]
+    );
+    assert!(
+        event
+            .read_attribute::<RemovedValidators>()
+            .unwrap()
+            .0
+            .is_empty()
+    );
+    assert!(
+        event
+            .read_attribute::<ValidatorPowerChanges>()
+            .unwrap()
+            .0
+            .is_empty()
+    );
+}
@@ -1,18 +1,23 @@
 //! Validator set updates
 
+use std::collections::BTreeMap;
+
 use namada_core::address::Address;
 use namada_core::arith::checked;
 use namada_core::chain::Epoch;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::key::PublicKeyTmRawHash;
 use namada_core::token;
+use namada_events::EmitEvents;
 use namada_systems::governance;
 use once_cell::unsync::Lazy;
 
+use crate::event::{PosEvent, ValidatorPowerChange};
 use crate::lazy_map::{NestedSubKey, SubKey};
 use crate::storage::{
     below_capacity_validator_set_handle, consensus_validator_set_handle,
-    get_num_consensus_validators, read_validator_stake,
+    get_num_consensus_validators,
+    read_consensus_validator_set_addresses_with_stake, read_validator_stake,
     validator_addresses_handle, validator_consensus_key_handle,
     validator_set_positions_handle, validator_state_handle,
 };
@@ -766,6 +771,75 @@ where
         .collect())
 }
 
+/// Emit a [`PosEvent::ValidatorSetChange`] event if the consensus validator
+/// set of `current_epoch` differs from the one of the epoch before it. No
+/// event is emitted if the set and the stakes of its validators are unchanged.
+pub fn emit_validator_set_change<S>(
+    storage: &S,
+    events: &mut impl EmitEvents,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead,
+{
+    let Some(prev_epoch) = current_epoch.prev() else {
+        return Ok(());
+    };
+    let prev_set: BTreeMap<Address, token::Amount> =
+        read_consensus_validator_set_addresses_with_stake(storage, prev_epoch)?
+            .into_iter()
+            .map(|validator| (validator.address, validator.bonded_stake))
+            .collect();
+    let new_set: BTreeMap<Address, token::Amount> =
+        read_consensus_validator_set_addresses_with_stake(
+            storage,
+            current_epoch,
+        )?
+        .into_iter()
+        .map(|validator| (validator.address, validator.bonded_stake))
+        .collect();
+
+    let added: Vec<Address> = new_set
+        .keys()
+        .filter(|address| !prev_set.contains_key(address))
+        .cloned()
+        .collect();
+    let removed: Vec<Address> = prev_set
+        .keys()
+        .filter(|address| !new_set.contains_key(address))
+        .cloned()
+        .collect();
+    let power_changes: Vec<ValidatorPowerChange> = new_set
+        .iter()
+        .filter_map(|(validator, new_stake)| {
+            let prev_stake = prev_set.get(validator)?;
+            (prev_stake != new_stake).then(|| ValidatorPowerChange {
+                validator: validator.clone(),
+                prev_stake: *prev_stake,
+                new_stake: *new_stake,
+            })
+        })
+        .collect();
+
+    if added.is_empty() && removed.is_empty() && power_changes.is_empty() {
+        return Ok(());
+    }
+    tracing::debug!(
+        "Consensus validator set changed in epoch {current_epoch}: {} added, \
+         {} removed, {} stake changes",
+        added.len(),
+        removed.len(),
+        power_changes.len()
+    );
+    events.emit(PosEvent::ValidatorSetChange {
+        epoch: current_epoch,
+        added,
+        removed,
+        power_changes,
+    });
+    Ok(())
+}
+
 /// Copy the consensus and below-capacity validator sets and positions into a
 /// future epoch. Also copies the epoched set of all known validators in the
 /// network.
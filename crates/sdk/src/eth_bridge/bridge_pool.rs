@@ -354,6 +354,22 @@ pub async fn query_bridge_pool(
     Ok(pool_contents)
 }
 
+/// Query the contents of the Ethereum bridge pool along with the
+/// keccak Merkle root over them, e.g. to build a signed batch.
+pub async fn query_bridge_pool_with_root(
+    client: &(impl Client + Sync),
+) -> Result<(Vec<PendingTransfer>, KeccakHash), Error> {
+    RPC.shell()
+        .eth_bridge()
+        .read_ethereum_bridge_pool_with_root(client)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                e.to_string(),
+            ))
+        })
+}
+
 /// Query the contents of the Ethereum bridge pool that
 /// is covered by the latest signed root.
 /// Prints out a json payload.
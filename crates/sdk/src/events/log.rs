@@ -1,11 +1,14 @@
 //! A log to store events emitted by `FinalizeBlock` calls in the ledger.
 //!
 //! The log will hold up to `N` events of a certain kind at a time, before
-//! resorting to pruning older events contained within.
+//! resorting to pruning older events contained within. Optionally, events
+//! older than a given number of block heights can be pruned as well.
 
 use circular_queue::CircularQueue;
+use namada_core::chain::BlockHeight;
 use patricia_tree::map::StringPatriciaMap;
 
+use super::extend::Height;
 use super::{EmitEvents, Event, EventType};
 
 pub mod dumb_queries;
@@ -19,6 +22,10 @@ pub struct Params {
     /// If the number of events of a given type in the log exceeds this value,
     /// events of that kind in the log will be pruned.
     pub max_log_events_per_kind: usize,
+    /// Number of most recent block heights whose events are retained in the
+    /// event log. If `None`, events are only pruned based on
+    /// [`Params::max_log_events_per_kind`].
+    pub retained_heights: Option<u64>,
 }
 
 impl Default for Params {
@@ -26,6 +33,7 @@ impl Default for Params {
         // TODO(namada#3237): tune the default params
         Self {
             max_log_events_per_kind: 50000,
+            retained_heights: None,
         }
     }
 }
@@ -35,6 +43,7 @@ impl Default for Params {
 #[derive(Debug)]
 pub struct EventLog {
     cap: usize,
+    retained_heights: Option<u64>,
     map: StringPatriciaMap<CircularQueue<Event>>,
 }
 
@@ -85,6 +94,7 @@ impl EventLog {
     pub fn new(params: Params) -> Self {
         Self {
             cap: params.max_log_events_per_kind,
+            retained_heights: params.retained_heights,
             map: StringPatriciaMap::new(),
         }
     }
@@ -102,6 +112,55 @@ impl EventLog {
         tracing::debug!(num_entries, "Added new entries to the event log");
     }
 
+    /// Prune all events emitted at a block height lower than
+    /// `before_height`. Events without a height attribute are kept.
+    pub fn prune_events(&mut self, before_height: BlockHeight) {
+        let mut num_pruned = 0;
+        for queue in self.map.values_mut() {
+            let is_old = |event: &Event| {
+                event
+                    .read_attribute::<Height>()
+                    .is_ok_and(|height| height < before_height)
+            };
+            if !queue.iter().any(is_old) {
+                continue;
+            }
+            let old_queue = std::mem::replace(
+                queue,
+                CircularQueue::with_capacity(self.cap),
+            );
+            for event in old_queue.asc_iter() {
+                if is_old(event) {
+                    num_pruned += 1;
+                } else {
+                    queue.push(event.clone());
+                }
+            }
+        }
+        tracing::debug!(
+            num_pruned,
+            %before_height,
+            "Pruned old entries from the event log"
+        );
+    }
+
+    /// Prune the events that fall outside of the configured retention
+    /// window, counting back from the last committed block height. The events
+    /// of `last_committed_height` itself are never pruned.
+    pub fn prune_to_retention(&mut self, last_committed_height: BlockHeight) {
+        let Some(retained_heights) = self.retained_heights else {
+            return;
+        };
+        let retained_heights = retained_heights.max(1);
+        let before_height = BlockHeight(
+            last_committed_height
+                .0
+                .saturating_sub(retained_heights)
+                .saturating_add(1),
+        );
+        self.prune_events(before_height);
+    }
+
     /// Returns a new iterator over this [`EventLog`].
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &Event> {
@@ -241,6 +300,7 @@ mod event_log_tests {
 
         let mut log = EventLog::new(Params {
             max_log_events_per_kind: LOG_CAP,
+            retained_heights: None,
         });
 
         // completely fill the log with events
@@ -278,4 +338,44 @@ mod event_log_tests {
             assert_eq!(mock_event(APPLIED_TX, format!("{i:064X}")), event);
         }
     }
+
+    /// Test pruning events older than the retained block heights.
+    #[test]
+    fn test_log_prune_by_height() {
+        const NUM_HEIGHTS: u64 = 10;
+        const RETAINED_HEIGHTS: u64 = 3;
+
+        let mut log = EventLog::new(Params {
+            max_log_events_per_kind: 1000,
+            retained_heights: Some(RETAINED_HEIGHTS),
+        });
+
+        for height in 1..=NUM_HEIGHTS {
+            for event in mock_tx_events(HASH) {
+                log.emit(event.with(Height(BlockHeight(height))));
+            }
+        }
+        // events without a height are never pruned by height
+        log.emit(mock_event(APPLIED_TX, HASH));
+
+        log.prune_to_retention(BlockHeight(NUM_HEIGHTS));
+
+        let mut heights: Vec<_> = log
+            .iter()
+            .filter_map(|event| event.read_attribute::<Height>().ok())
+            .map(|height| height.0)
+            .collect();
+        heights.sort_unstable();
+        assert_eq!(heights, vec![8, 8, 9, 9, 10, 10]);
+        assert_eq!(log.iter().count(), 7);
+
+        // the last committed height is always retained
+        log.prune_events(BlockHeight(NUM_HEIGHTS));
+        let heights: Vec<_> = log
+            .iter()
+            .filter_map(|event| event.read_attribute::<Height>().ok())
+            .map(|height| height.0)
+            .collect();
+        assert_eq!(heights, vec![10, 10]);
+    }
 }
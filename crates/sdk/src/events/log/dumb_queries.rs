@@ -16,7 +16,8 @@ use namada_ibc::event::{
 };
 
 use crate::events::extend::{
-    ExtendAttributesMap, ExtendEventAttributes, TxHash as TxHashAttr,
+    EventAttributeEntry, ExtendAttributesMap, ExtendEventAttributes,
+    TxHash as TxHashAttr,
 };
 use crate::events::{Event, EventToEmit, EventType, EventTypeBuilder};
 use crate::ibc::core::client::types::Height as IbcHeight;
@@ -32,6 +33,7 @@ pub struct QueryMatcher {
     event_type_match: MatchType,
     event_type: EventType,
     attributes: HashMap<String, String>,
+    attribute_prefixes: HashMap<String, String>,
 }
 
 /// Determine which kind of match will be performed over a series of event
@@ -80,6 +82,7 @@ impl QueryMatcher {
             event_type: prefix,
             event_type_match: MatchType::Prefix,
             attributes: Default::default(),
+            attribute_prefixes: Default::default(),
         }
     }
 
@@ -89,6 +92,7 @@ impl QueryMatcher {
             event_type,
             event_type_match: MatchType::Exact,
             attributes: Default::default(),
+            attribute_prefixes: Default::default(),
         }
     }
 
@@ -102,6 +106,21 @@ impl QueryMatcher {
         self
     }
 
+    /// Add a new attribute to the [`QueryMatcher`], whose value
+    /// in matched events must start with the given `prefix`.
+    #[inline]
+    pub fn and_attribute_prefix<'value, DATA>(
+        mut self,
+        prefix: impl Into<String>,
+    ) -> Self
+    where
+        DATA: EventAttributeEntry<'value>,
+    {
+        self.attribute_prefixes
+            .insert(DATA::KEY.to_string(), prefix.into());
+        self
+    }
+
     /// Checks if this [`QueryMatcher`] validates the
     /// given [`Event`].
     pub fn matches(&self, event: &Event) -> bool {
@@ -114,6 +133,7 @@ impl QueryMatcher {
             return false;
         }
         event.has_subset_of_attrs(&self.attributes)
+            && event.has_attrs_with_prefixes(&self.attribute_prefixes)
     }
 
     /// Returns a query matching the given relayed Bridge pool transaction hash.
@@ -174,7 +194,9 @@ impl QueryMatcher {
 
 #[cfg(test)]
 mod tests {
+    use namada_core::address::testing::{established_address_1, nam};
     use namada_ethereum_bridge::event::EthBridgeEvent;
+    use namada_token::event::TokenAddress;
     use namada_token::event::types::TRANSFER;
     use namada_tx::event::masp_types::TRANSFER as MASP_TRANSFER;
 
@@ -279,4 +301,33 @@ mod tests {
             }
         }
     }
+
+    /// Test if matching the prefix of an attribute value works as expected.
+    #[test]
+    fn test_query_matching_attribute_prefix() {
+        let token = nam();
+        let token_str = token.to_string();
+        let matcher = QueryMatcher::with_event_type(TRANSFER)
+            .and_attribute_prefix::<TokenAddress>(&token_str[..10]);
+
+        let event_1: Event = Event::new(TRANSFER, EventLevel::Tx)
+            .with(TokenAddress(token))
+            .into();
+        assert!(matcher.matches(&event_1));
+
+        let event_2: Event = Event::new(TRANSFER, EventLevel::Tx)
+            .with(TokenAddress(established_address_1()))
+            .into();
+        assert!(!matcher.matches(&event_2));
+
+        // Events missing the attribute are not matched
+        let event_3 = Event::new(TRANSFER, EventLevel::Tx);
+        assert!(!matcher.matches(&event_3));
+
+        // Events of another type are not matched
+        let event_4: Event = Event::new(MASP_TRANSFER, EventLevel::Tx)
+            .with(TokenAddress(nam()))
+            .into();
+        assert!(!matcher.matches(&event_4));
+    }
 }
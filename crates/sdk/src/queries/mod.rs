@@ -4,7 +4,7 @@
 // Re-export to show in rustdoc!
 use namada_state::{DB, DBIter, StorageHasher};
 use shell::SHELL;
-pub use shell::Shell;
+pub use shell::{EpochInfo, GenesisInfo, Shell};
 pub use types::{
     EncodedResponseQuery, Error, RequestCtx, RequestQuery, ResponseQuery,
     Router,
@@ -1,25 +1,31 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub(super) mod eth_bridge;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
 use namada_account::{Account, AccountPublicKeysMap};
 use namada_core::address::Address;
 use namada_core::arith::checked;
-use namada_core::chain::{BlockHeader, BlockHeight, Epoch};
+use namada_core::chain::{BlockHeader, BlockHeight, ChainId, Epoch};
 use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::hints;
+use namada_core::key::common;
 use namada_core::masp::{MaspEpoch, TokenMap};
+use namada_core::parameters::Parameters;
 use namada_core::storage::{self, BlockResults, KeySeg, PrefixValue};
-use namada_core::time::DurationSecs;
+use namada_core::time::{DateTimeUtc, DurationSecs};
 use namada_core::token::{Denomination, MaspDigitPos};
 use namada_core::uint::Uint;
 use namada_ibc::event::IbcEventType;
-use namada_state::{DB, DBIter, LastBlock, StateRead, StorageHasher};
+use namada_proof_of_stake::storage::read_consensus_validator_set_addresses_with_stake;
+use namada_proof_of_stake::types::WeightedValidator;
+use namada_state::{
+    DB, DBIter, LastBlock, StateRead, StorageHasher, StoreType,
+};
 use namada_storage::{ResultExt, StorageRead};
 use namada_token::masp::MaspTokenRewardData;
 use namada_token::storage_key::masp_token_map_key;
@@ -29,6 +35,7 @@ use namada_tx::event::types::APPLIED;
 use self::eth_bridge::{ETH_BRIDGE, EthBridge};
 use crate::borsh::BorshSerializeExt;
 use crate::events::Event;
+use crate::events::extend::{Height as HeightAttr, TxHash as TxHashAttr};
 use crate::events::log::dumb_queries;
 use crate::ibc::core::host::types::identifiers::{
     ChannelId, ClientId, PortId, Sequence,
@@ -55,6 +62,33 @@ type Conversion = (
     MerklePath<Node>,
 );
 
+type AccountKeys = (Vec<common::PublicKey>, u8);
+
+/// The boundaries of an epoch
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct EpochInfo {
+    /// Height of the first block of the epoch
+    pub start_height: BlockHeight,
+    /// Time of the first block of the epoch, if its header is available
+    pub start_time: Option<DateTimeUtc>,
+    /// Height of the last block of the epoch. This is `None` for the current
+    /// epoch, as it's not yet known when it will end.
+    pub end_height: Option<BlockHeight>,
+}
+
+/// The genesis configuration of the chain that is available on-chain
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct GenesisInfo {
+    /// The chain ID
+    pub chain_id: ChainId,
+    /// The genesis time. This is `None` for chains that didn't store it at
+    /// genesis and whose first block header is no longer available.
+    pub genesis_time: Option<DateTimeUtc>,
+    /// The consensus validators of the genesis epoch with their stake. This
+    /// is empty once the genesis validator set has been pruned from storage.
+    pub genesis_validators: BTreeSet<WeightedValidator>,
+}
+
 router! {SHELL,
     // Shell provides storage read access, block metadata and can dry-run a tx
 
@@ -70,12 +104,24 @@ router! {SHELL,
     // The address of the native token
     ( "native_token" ) -> Address = native_token,
 
+    // The current protocol parameters
+    ( "parameters" ) -> Parameters = parameters,
+
     // Epoch of the input block height
     ( "epoch_at_height" / [height: BlockHeight]) -> Option<Epoch> = epoch_at_height,
 
+    // Boundaries of the given epoch
+    ( "epoch_info" / [epoch: Epoch]) -> Option<EpochInfo> = epoch_info,
+
+    // The genesis configuration of the chain
+    ( "genesis_info" ) -> GenesisInfo = genesis_info,
+
     // Query the last committed block
     ( "last_block" ) -> Option<LastBlock> = last_block,
 
+    // Merkle root of the given sub-tree at the last committed height
+    ( "store_root" / [store: StoreType] ) -> Hash = store_root,
+
     // First block height of the current epoch
     ( "first_block_height_of_current_epoch" ) -> BlockHeight = first_block_height_of_current_epoch,
 
@@ -109,18 +155,30 @@ router! {SHELL,
     // was the transaction applied?
     ( "applied" / [tx_hash: Hash] ) -> Option<TxAppliedEvents> = applied,
 
+    // All the events emitted by the transaction with the given hash
+    ( "tx_events" / [tx_hash: Hash] ) -> Vec<Event> = tx_events,
+
+    // Hashes of the transactions applied at the given height (default latest)
+    ( "block_txs" / [height: opt BlockHeight] ) -> Vec<Hash> = block_txs,
+
     // Query account subspace
     ( "account" / [owner: Address] ) -> Option<Account> = account,
 
     // Query public key revealad
     ( "revealed" / [owner: Address] ) -> bool = revealed,
 
+    // Query the public keys controlling an account and its threshold
+    ( "account_keys" / [owner: Address] ) -> AccountKeys = account_keys,
+
     // IBC UpdateClient event
     ( "ibc_client_update" / [client_id: ClientId] / [consensus_height: BlockHeight] ) -> Option<Event> = ibc_client_update,
 
     // IBC packet event
     ( "ibc_packet" / [event_type: IbcEventType] / [source_port: PortId] / [source_channel: ChannelId] / [destination_port: PortId] / [destination_channel: ChannelId] / [sequence: Sequence]) -> Option<Event> = ibc_packet,
 
+    // IBC packets sent on a channel still awaiting an acknowledgement
+    ( "ibc_pending_packets" / [port_id: PortId] / [channel_id: ChannelId] ) -> Vec<(Sequence, Vec<u8>)> = ibc_pending_packets,
+
     // Get the block header associated with the requested height
     ( "block_header" / [height: BlockHeight] ) -> Option<BlockHeader> = block_header,
 
@@ -386,6 +444,16 @@ where
     Ok(data)
 }
 
+fn parameters<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<Parameters>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_parameters::read(ctx.state)
+}
+
 fn epoch_at_height<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     height: BlockHeight,
@@ -397,6 +465,73 @@ where
     Ok(ctx.state.in_mem().block.pred_epochs.get_epoch(height))
 }
 
+fn epoch_info<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    epoch: Epoch,
+) -> namada_storage::Result<Option<EpochInfo>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let pred_epochs = &ctx.state.in_mem().block.pred_epochs;
+    let Some(start_height) = pred_epochs.get_start_height_of_epoch(epoch)
+    else {
+        return Ok(None);
+    };
+    // An epoch ends right before the next one starts, which is only known
+    // once it has been closed
+    let end_height = pred_epochs
+        .get_start_height_of_epoch(epoch.next())
+        .and_then(|next_start_height| next_start_height.prev_height());
+    let start_time = ctx
+        .state
+        .db()
+        .read_block_header(start_height)?
+        .map(|header| header.time);
+    Ok(Some(EpochInfo {
+        start_height,
+        start_time,
+        end_height,
+    }))
+}
+
+fn genesis_info<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<GenesisInfo>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let in_mem = ctx.state.in_mem();
+    let genesis_epoch = Epoch::default();
+    // Fall back to the time of the first block for chains that didn't store
+    // the genesis time
+    let genesis_time = match namada_parameters::read_genesis_time(ctx.state)? {
+        Some(genesis_time) => Some(genesis_time),
+        None => match in_mem
+            .block
+            .pred_epochs
+            .get_start_height_of_epoch(genesis_epoch)
+        {
+            Some(first_height) => ctx
+                .state
+                .db()
+                .read_block_header(first_height)?
+                .map(|header| header.time),
+            None => None,
+        },
+    };
+    let genesis_validators = read_consensus_validator_set_addresses_with_stake(
+        ctx.state,
+        genesis_epoch,
+    )?;
+    Ok(GenesisInfo {
+        chain_id: in_mem.chain_id.clone(),
+        genesis_time,
+        genesis_validators,
+    })
+}
+
 fn last_block<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<Option<LastBlock>>
@@ -407,6 +542,19 @@ where
     Ok(ctx.state.in_mem().last_block.clone())
 }
 
+/// Returns the root of the given merkle sub-tree. Between blocks, the
+/// in-memory tree holds the state of the last committed block.
+fn store_root<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    store: StoreType,
+) -> namada_storage::Result<Hash>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Ok(ctx.state.in_mem().block.tree.sub_root(&store).into())
+}
+
 fn first_block_height_of_current_epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<BlockHeight>
@@ -604,6 +752,53 @@ where
         }))
 }
 
+fn tx_events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    tx_hash: Hash,
+) -> namada_storage::Result<Vec<Event>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let matcher = dumb_queries::QueryMatcher::tx_events(tx_hash);
+    Ok(ctx
+        .event_log
+        .with_matcher(matcher)
+        .iter()
+        .cloned()
+        .collect())
+}
+
+fn block_txs<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    height: Option<BlockHeight>,
+) -> namada_storage::Result<Vec<Hash>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let last_committed_height = ctx.state.in_mem().get_last_block_height();
+    let height = height.unwrap_or(last_committed_height);
+    if height > last_committed_height {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "Cannot query the transactions of block {height}, the last \
+             committed block is {last_committed_height}"
+        )));
+    }
+
+    let matcher = dumb_queries::QueryMatcher::with_event_type(APPLIED)
+        .and_attribute(HeightAttr(height));
+    let mut tx_hashes = ctx
+        .event_log
+        .with_matcher(matcher)
+        .iter()
+        .map(|event| event.read_attribute::<TxHashAttr>().into_storage_result())
+        .collect::<namada_storage::Result<Vec<_>>>()?;
+    // The event log yields the most recent events first
+    tx_hashes.reverse();
+    Ok(tx_hashes)
+}
+
 fn ibc_client_update<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     client_id: ClientId,
@@ -644,6 +839,22 @@ where
     Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
 }
 
+fn ibc_pending_packets<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    port_id: PortId,
+    channel_id: ChannelId,
+) -> namada_storage::Result<Vec<(Sequence, Vec<u8>)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_ibc::storage::pending_packet_commitments(
+        ctx.state,
+        &port_id,
+        &channel_id,
+    )
+}
+
 fn account<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     owner: Address,
@@ -681,12 +892,38 @@ where
     Ok(!public_keys.is_empty())
 }
 
+fn account_keys<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<AccountKeys>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_account::account_keys(ctx.state, &owner)
+}
+
 #[cfg(test)]
 mod test {
     use namada_core::address;
+    use namada_core::chain::{BlockHeader, BlockHeight, Epoch};
+    use namada_core::hash::Hash;
+    use namada_core::key::RefTo;
+    use namada_core::parameters::Parameters;
+    use namada_core::time::DateTimeUtc;
+    use namada_ibc::storage::commitment_key;
+    use namada_state::StoreType;
+    use namada_storage::StorageWrite;
     use namada_token::storage_key::balance_key;
 
+    use super::{
+        APPLIED, ChannelId, EpochInfo, GenesisInfo, HeightAttr, PortId,
+        Sequence, TxHashAttr,
+    };
+    use crate::events::extend::ComposeEvent;
+    use crate::events::{Event, EventLevel, EventType};
     use crate::queries::RPC;
+    use crate::queries::testing::TestClient;
 
     #[test]
     fn test_shell_queries_router_paths() {
@@ -708,4 +945,349 @@ mod test {
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
     }
+
+    #[tokio::test]
+    async fn test_native_token_query() {
+        let mut client = TestClient::new(RPC);
+        // Set the native token as it would be from genesis
+        let native_token = address::testing::established_address_1();
+        client.state.in_mem_mut().native_token = native_token.clone();
+
+        let path = RPC.shell().native_token_path();
+        assert_eq!("/shell/native_token", path);
+
+        let queried = RPC.shell().native_token(&client).await.unwrap();
+        assert_eq!(queried, native_token);
+
+        let queried = crate::rpc::query_native_token(&client).await.unwrap();
+        assert_eq!(queried, native_token);
+    }
+
+    #[tokio::test]
+    async fn test_genesis_info_query() {
+        let mut client = TestClient::new(RPC);
+        let path = RPC.shell().genesis_info_path();
+        assert_eq!("/shell/genesis_info", path);
+
+        // A chain without stored genesis metadata
+        let info = crate::rpc::query_genesis_info(&client).await.unwrap();
+        assert_eq!(
+            info,
+            GenesisInfo {
+                chain_id: client.state.in_mem().chain_id.clone(),
+                genesis_time: None,
+                genesis_validators: Default::default(),
+            }
+        );
+
+        // Initialize the genesis as the node would
+        let genesis_time = DateTimeUtc::from_unix_timestamp(1_000).unwrap();
+        namada_parameters::write_genesis_time(&mut client.state, genesis_time)
+            .unwrap();
+        let genesis_validator =
+            namada_proof_of_stake::test_utils::get_dummy_genesis_validator();
+        let validator = genesis_validator.address.clone();
+        let stake = genesis_validator.tokens;
+        namada_proof_of_stake::test_utils::test_init_genesis::<
+            _,
+            namada_parameters::Store<_>,
+            namada_governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            namada_proof_of_stake::OwnedPosParams::default(),
+            std::iter::once(genesis_validator),
+            Epoch(0),
+        )
+        .unwrap();
+        client.state.commit_block().unwrap();
+
+        let info = crate::rpc::query_genesis_info(&client).await.unwrap();
+        assert_eq!(info.chain_id, client.state.in_mem().chain_id);
+        assert_eq!(info.genesis_time, Some(genesis_time));
+        let validators: Vec<_> = info
+            .genesis_validators
+            .into_iter()
+            .map(|validator| (validator.address, validator.bonded_stake))
+            .collect();
+        assert_eq!(validators, vec![(validator, stake)]);
+    }
+
+    #[tokio::test]
+    async fn test_parameters_query() {
+        let mut client = TestClient::new(RPC);
+        // Initialize the parameters as they would be from genesis
+        let genesis_params = Parameters {
+            max_block_gas: 20_000_000,
+            epochs_per_year: 105_120,
+            implicit_vp_code_hash: Some(Hash::zero()),
+            ..Default::default()
+        };
+        namada_parameters::init_storage(&genesis_params, &mut client.state)
+            .unwrap();
+        client.state.commit_block().unwrap();
+
+        let path = RPC.shell().parameters_path();
+        assert_eq!("/shell/parameters", path);
+
+        let queried = crate::rpc::query_parameters(&client).await.unwrap();
+        assert_eq!(queried, genesis_params);
+        assert_eq!(queried.max_block_gas, 20_000_000);
+        assert_eq!(queried.epochs_per_year, 105_120);
+
+        // The parameters can be rendered as JSON
+        let json = serde_json::to_string(&queried).unwrap();
+        let decoded: Parameters = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, genesis_params);
+    }
+
+    #[tokio::test]
+    async fn test_ibc_pending_packets_query() {
+        let mut client = TestClient::new(RPC);
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+        let other_channel_id = ChannelId::new(1);
+
+        // Seed the commitments of packets sent with sequences 1 to 11, and
+        // delete those of the acknowledged ones
+        for seq in 1..=11_u64 {
+            let key = commitment_key(&port_id, &channel_id, seq.into());
+            client
+                .state
+                .write_bytes(&key, [u8::try_from(seq).unwrap()])
+                .unwrap();
+        }
+        for seq in [2_u64, 5] {
+            let key = commitment_key(&port_id, &channel_id, seq.into());
+            client.state.delete(&key).unwrap();
+        }
+        // A packet on another channel
+        let key = commitment_key(&port_id, &other_channel_id, 1.into());
+        client.state.write_bytes(&key, [0]).unwrap();
+        client.state.commit_block().unwrap();
+
+        let pending =
+            crate::rpc::query_pending_packets(&client, &port_id, &channel_id)
+                .await
+                .unwrap();
+        let expected: Vec<_> = [1_u64, 3, 4, 6, 7, 8, 9, 10, 11]
+            .into_iter()
+            .map(|seq| (Sequence::from(seq), vec![u8::try_from(seq).unwrap()]))
+            .collect();
+        assert_eq!(pending, expected);
+
+        // An unknown channel has no pending packets
+        let pending = crate::rpc::query_pending_packets(
+            &client,
+            &port_id,
+            &ChannelId::new(2),
+        )
+        .await
+        .unwrap();
+        assert!(pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_epoch_info_query() {
+        let mut client = TestClient::new(RPC);
+
+        // Seed the epoch boundaries - epoch 0 starts at height 1, epoch 1 at
+        // height 4 and the current epoch 2 at height 7
+        let epoch_start_heights = [1, 4, 7];
+        for height in 1..=8 {
+            let in_mem = client.state.in_mem_mut();
+            in_mem.block.height = BlockHeight(height);
+            if epoch_start_heights.contains(&height) {
+                if height != 1 {
+                    in_mem.block.epoch = in_mem.block.epoch.next();
+                }
+                in_mem.block.pred_epochs.new_epoch(BlockHeight(height));
+            }
+            in_mem.header = Some(BlockHeader {
+                hash: Hash::default(),
+                time: DateTimeUtc::from_unix_timestamp(
+                    i64::try_from(height * 10).unwrap(),
+                )
+                .unwrap(),
+                next_validators_hash: Hash::default(),
+            });
+            client.state.commit_block().unwrap();
+        }
+
+        let expected = [
+            (Epoch(0), 1, 10, Some(BlockHeight(3))),
+            (Epoch(1), 4, 40, Some(BlockHeight(6))),
+            // The end of the current epoch is not known yet
+            (Epoch(2), 7, 70, None),
+        ];
+        for (epoch, start_height, start_time, end_height) in expected {
+            let info = RPC
+                .shell()
+                .epoch_info(&client, &epoch)
+                .await
+                .unwrap()
+                .expect("The epoch should be known");
+            assert_eq!(
+                info,
+                EpochInfo {
+                    start_height: BlockHeight(start_height),
+                    start_time: DateTimeUtc::from_unix_timestamp(start_time),
+                    end_height,
+                }
+            );
+        }
+
+        // A future epoch is not known
+        let info = RPC.shell().epoch_info(&client, &Epoch(3)).await.unwrap();
+        assert!(info.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_store_root_query() {
+        let mut client = TestClient::new(RPC);
+
+        let path = RPC.shell().store_root_path(&StoreType::Account);
+        assert_eq!("/shell/store_root/account", path);
+
+        let account_root =
+            crate::rpc::query_store_root(&client, StoreType::Account)
+                .await
+                .unwrap();
+        let pos_root = crate::rpc::query_store_root(&client, StoreType::PoS)
+            .await
+            .unwrap();
+
+        // Seed the account store
+        let token_addr = address::testing::established_address_1();
+        let owner = address::testing::established_address_2();
+        client
+            .state
+            .write(&balance_key(&token_addr, &owner), 100_u64)
+            .unwrap();
+        client.state.commit_block().unwrap();
+
+        let new_account_root =
+            crate::rpc::query_store_root(&client, StoreType::Account)
+                .await
+                .unwrap();
+        assert_ne!(account_root, new_account_root);
+        assert_eq!(
+            new_account_root,
+            client
+                .state
+                .in_mem()
+                .block
+                .tree
+                .sub_root(&StoreType::Account)
+                .into()
+        );
+        // Other sub-trees are unaffected
+        let new_pos_root =
+            crate::rpc::query_store_root(&client, StoreType::PoS)
+                .await
+                .unwrap();
+        assert_eq!(pos_root, new_pos_root);
+    }
+
+    #[tokio::test]
+    async fn test_block_txs_query() {
+        let mut client = TestClient::new(RPC);
+        let hash_1 = Hash::sha256(b"tx 1");
+        let hash_2 = Hash::sha256(b"tx 2");
+        let hash_3 = Hash::sha256(b"tx 3");
+
+        // Apply two txs in block 1 and one in block 2
+        let applied = |hash: Hash, height: u64| -> Event {
+            Event::new(APPLIED, EventLevel::Tx)
+                .with(TxHashAttr(hash))
+                .with(HeightAttr(BlockHeight(height)))
+                .into()
+        };
+        client.event_log.log_events([
+            applied(hash_1, 1),
+            applied(hash_2, 1),
+            applied(hash_3, 2),
+        ]);
+        client.state.in_mem_mut().block.height = BlockHeight(2);
+        client.state.commit_block().unwrap();
+
+        let txs = crate::rpc::query_block_txs(&client, Some(BlockHeight(1)))
+            .await
+            .unwrap();
+        assert_eq!(txs, vec![hash_1, hash_2]);
+
+        // Defaults to the last committed block
+        let txs = crate::rpc::query_block_txs(&client, None).await.unwrap();
+        assert_eq!(txs, vec![hash_3]);
+
+        // Blocks that have not been committed yet are rejected
+        assert!(
+            crate::rpc::query_block_txs(&client, Some(BlockHeight(3)))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tx_events_query() {
+        let mut client = TestClient::new(RPC);
+        let tx_hash = Hash::sha256(b"tx");
+        let other_tx_hash = Hash::sha256(b"other tx");
+
+        // A tx that emits two events, and another tx's event in between
+        let first: Event = Event::new(EventType::new("first"), EventLevel::Tx)
+            .with(TxHashAttr(tx_hash))
+            .into();
+        let other: Event = Event::new(APPLIED, EventLevel::Tx)
+            .with(TxHashAttr(other_tx_hash))
+            .into();
+        let second: Event = Event::new(APPLIED, EventLevel::Tx)
+            .with(TxHashAttr(tx_hash))
+            .into();
+        client.event_log.log_events([
+            first.clone(),
+            other.clone(),
+            second.clone(),
+        ]);
+
+        let events = crate::rpc::query_events_for_tx(&client, tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&first));
+        assert!(events.contains(&second));
+
+        let events = crate::rpc::query_events_for_tx(&client, other_tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(events, vec![other]);
+
+        // An unknown tx has no events
+        let events = crate::rpc::query_events_for_tx(
+            &client,
+            Hash::sha256(b"unknown tx"),
+        )
+        .await
+        .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_account_keys_query() {
+        let mut client = TestClient::new(RPC);
+        let pk = namada_core::key::testing::keypair_1().ref_to();
+        let owner = address::Address::from(&pk);
+
+        // An unrevealed implicit account has no keys and a threshold of 1
+        let keys = crate::rpc::query_account_keys(&client, &owner)
+            .await
+            .unwrap();
+        assert_eq!(keys, (vec![], 1));
+
+        namada_account::reveal_pk(&mut client.state, &pk).unwrap();
+        let keys = crate::rpc::query_account_keys(&client, &owner)
+            .await
+            .unwrap();
+        assert_eq!(keys, (vec![pk], 1));
+    }
 }
@@ -133,6 +133,10 @@ pub type TransferToErcArgs = (
     ethereum_structs::RelayProof,
 );
 
+/// The contents of the Ethereum bridge pool and the keccak Merkle root
+/// over them.
+pub type BridgePoolWithRoot = (Vec<PendingTransfer>, KeccakHash);
+
 /// Response data returned by `generate_bridge_pool_proof`.
 #[derive(
     Debug,
@@ -169,6 +173,11 @@ router! {ETH_BRIDGE,
     ( "pool" / "contents" )
         -> Vec<PendingTransfer> = read_ethereum_bridge_pool,
 
+    // Get the current contents of the Ethereum bridge pool along
+    // with the keccak Merkle root over them
+    ( "pool" / "contents_with_root" )
+        -> BridgePoolWithRoot = read_ethereum_bridge_pool_with_root,
+
     // Get the contents of the Ethereum bridge pool covered by
     // the latest signed Merkle tree root.
     ( "pool" / "signed_contents" )
@@ -415,6 +424,23 @@ where
 fn read_ethereum_bridge_pool<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<Vec<PendingTransfer>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let (transfers, _root) = read_ethereum_bridge_pool_at_height(
+        ctx.state.in_mem().get_last_block_height(),
+        ctx,
+    );
+    Ok(transfers)
+}
+
+/// Read the current contents of the Ethereum bridge pool and the
+/// keccak Merkle root over them. The root of an empty pool is the
+/// zero hash.
+fn read_ethereum_bridge_pool_with_root<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<BridgePoolWithRoot>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -443,14 +469,16 @@ where
             "No signed root for the Ethereum bridge pool exists in storage.",
         ))
         .into_storage_result()?;
-    Ok(read_ethereum_bridge_pool_at_height(height, ctx))
+    let (transfers, _root) = read_ethereum_bridge_pool_at_height(height, ctx);
+    Ok(transfers)
 }
 
-/// Read the Ethereum bridge pool contents at a specified height.
+/// Read the Ethereum bridge pool contents and their Merkle root at a
+/// specified height.
 fn read_ethereum_bridge_pool_at_height<D, H, V, T>(
     height: BlockHeight,
     ctx: RequestCtx<'_, D, H, V, T>,
-) -> Vec<PendingTransfer>
+) -> (Vec<PendingTransfer>, KeccakHash)
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -479,7 +507,10 @@ where
             PendingTransfer::try_from_slice(&value).unwrap()
         })
         .collect();
-    transfers
+    (
+        transfers,
+        merkle_tree.sub_root(&StoreType::BridgePool).into(),
+    )
 }
 
 /// Generate a merkle proof for the inclusion of the
@@ -845,6 +876,7 @@ mod test_ethbridge_router {
     use namada_core::eth_bridge_pool::{
         GasFee, TransferToEthereum, TransferToEthereumKind,
     };
+    use namada_core::storage::KeySeg;
     use namada_core::voting_power::EthBridgeVotingPower;
     use namada_ethereum_bridge::protocol::transactions::validator_set_update::aggregate_votes;
     use namada_ethereum_bridge::storage::bridge_pool::{
@@ -1092,6 +1124,77 @@ mod test_ethbridge_router {
         assert_eq!(pool, Vec::from([transfer]));
     }
 
+    /// Test that the bridge pool contents are returned
+    /// along with the Merkle root over them
+    #[tokio::test]
+    async fn test_read_bridge_pool_with_root() {
+        let mut client = TestClient::new(RPC);
+        client.state.in_mem_mut().block.height = 1.into();
+        client.state.commit_block().expect("Test failed");
+
+        // an empty pool has the zero root
+        let (pool, root) = RPC
+            .shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool_with_root(&client)
+            .await
+            .unwrap();
+        assert!(pool.is_empty());
+        assert_eq!(root, KeccakHash::default());
+
+        let transfers: Vec<_> = [1u64, 2]
+            .into_iter()
+            .map(|amount| PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind: TransferToEthereumKind::Erc20,
+                    asset: EthAddress([0; 20]),
+                    recipient: EthAddress([0; 20]),
+                    sender: bertha_address(),
+                    amount: amount.into(),
+                },
+                gas_fee: GasFee {
+                    token: nam(),
+                    amount: 0.into(),
+                    payer: bertha_address(),
+                },
+            })
+            .collect();
+
+        // write the transfers into the bridge pool
+        client.state.in_mem_mut().block.height = 2.into();
+        let mut expected_tree =
+            BridgePoolTree::new(Default::default(), BTreeMap::new());
+        for transfer in &transfers {
+            client
+                .state
+                .write(&get_pending_key(transfer), transfer)
+                .expect("Test failed");
+            // the tree is keyed by the transfer hashes only
+            expected_tree
+                .insert_key(
+                    &Key::from(transfer.keccak256().to_db_key()),
+                    2.into(),
+                )
+                .expect("Test failed");
+        }
+
+        // commit the changes and increase block height
+        client.state.commit_block().expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+
+        // check the response
+        let (pool, root) = RPC
+            .shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool_with_root(&client)
+            .await
+            .unwrap();
+        let mut expected_pool = transfers;
+        expected_pool.sort_by_key(PendingTransfer::keccak256);
+        assert_eq!(pool, expected_pool);
+        assert_eq!(root, expected_tree.root());
+    }
+
     /// Test that reading the bridge pool always gets
     /// the latest pool
     #[tokio::test]
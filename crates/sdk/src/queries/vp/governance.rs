@@ -1,8 +1,20 @@
 // cd namada && cargo expand ledger::queries::vp::governance
 
+use borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::address::Address;
+use namada_core::arith::checked;
+use namada_core::chain::Epoch;
 use namada_governance::parameters::GovernanceParameters;
+use namada_governance::storage::keys as gov_keys;
 use namada_governance::storage::proposal::StorageProposal;
-use namada_governance::utils::{ProposalResult, Vote};
+use namada_governance::utils::{
+    ProposalResult, ProposalStatus, TallyType, Vote, VotePower,
+    compute_proposal_result,
+};
+use namada_proof_of_stake::queries::find_delegations;
+use namada_proof_of_stake::storage::{
+    read_pos_params, read_total_active_stake, read_validator_stake,
+};
 use namada_state::{DB, DBIter, StorageHasher};
 
 use crate::queries::types::RequestCtx;
@@ -11,6 +23,9 @@ use crate::queries::types::RequestCtx;
 router! {GOV,
     ( "proposal" / [id: u64 ] ) -> Option<StorageProposal> = proposal_id,
     ( "proposal" / [id: u64 ] / "votes" ) -> Vec<Vote> = proposal_id_votes,
+    ( "proposal" / [id: u64 ] / "view" ) -> Option<ProposalView> = proposal_view,
+    ( "proposal" / [id: u64 ] / "voting_power" / [owner: Address] )
+        -> VotePower = proposal_voting_power,
     ( "parameters" ) -> GovernanceParameters = parameters,
     ( "stored_proposal_result" / [id: u64] ) -> Option<ProposalResult> = proposal_result,
 }
@@ -27,6 +42,80 @@ where
     namada_governance::storage::get_proposal_by_id(ctx.state, id)
 }
 
+/// A proposal together with its status and the voting power of each kind of
+/// vote that it has received so far
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ProposalView {
+    /// The proposal, including its content and voting epochs
+    pub proposal: StorageProposal,
+    /// The status of the proposal at the current epoch
+    pub status: ProposalStatus,
+    /// The voting power of the yay votes
+    pub yay_power: VotePower,
+    /// The voting power of the nay votes
+    pub nay_power: VotePower,
+    /// The voting power of the abstain votes
+    pub abstain_power: VotePower,
+}
+
+/// Query the provided proposal id together with its status and the voting
+/// power of its votes. The voting power of an ended proposal is read from its
+/// stored result, once the proposal has been tallied at its activation epoch.
+/// Otherwise, the votes are tallied with the voting power at the proposal's
+/// voting end epoch, as they are when the proposal ends.
+fn proposal_view<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<Option<ProposalView>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let Some(proposal) =
+        namada_governance::storage::get_proposal_by_id(ctx.state, id)?
+    else {
+        return Ok(None);
+    };
+    let status = proposal.get_status(ctx.state.in_mem().last_epoch);
+    let stored_result = match status {
+        ProposalStatus::Ended => {
+            namada_governance::storage::get_proposal_result(ctx.state, id)?
+        }
+        ProposalStatus::Pending | ProposalStatus::OnGoing => None,
+    };
+    if let Some(result) = stored_result {
+        return Ok(Some(ProposalView {
+            proposal,
+            status,
+            yay_power: result.total_yay_power,
+            nay_power: result.total_nay_power,
+            abstain_power: result.total_abstain_power,
+        }));
+    }
+    let epoch = proposal.voting_end_epoch;
+    let votes = namada_governance::compute_proposal_votes::<
+        _,
+        namada_proof_of_stake::Store<_>,
+    >(ctx.state, id, epoch)?;
+    let params = read_pos_params::<_, namada_governance::Store<_>>(ctx.state)?;
+    let total_voting_power =
+        read_total_active_stake(ctx.state, &params, epoch)?;
+    let is_steward = namada_governance::pgf::storage::is_steward(
+        ctx.state,
+        &proposal.author,
+    )?;
+    let tally_type = TallyType::from(proposal.r#type.clone(), is_steward);
+    let result =
+        compute_proposal_result(votes, total_voting_power, tally_type)?;
+    Ok(Some(ProposalView {
+        proposal,
+        status,
+        yay_power: result.total_yay_power,
+        nay_power: result.total_nay_power,
+        abstain_power: result.total_abstain_power,
+    }))
+}
+
 /// Query all the votes for the given proposal id
 fn proposal_id_votes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -39,6 +128,39 @@ where
     namada_governance::storage::get_proposal_votes(ctx.state, id)
 }
 
+/// Get the voting power of the given address on the provided proposal id,
+/// computed at the proposal's voting end epoch, at which votes are tallied. A
+/// validator's voting power is its stake, comprising its self-bond and the
+/// delegations it received, while any other address' voting power is the sum
+/// of its delegations. An address without any stake has no voting power.
+fn proposal_voting_power<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+    owner: Address,
+) -> namada_storage::Result<VotePower>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch: Epoch = namada_storage::read_required(
+        ctx.state,
+        &gov_keys::get_voting_end_epoch_key(id),
+    )?;
+    if namada_proof_of_stake::is_validator(ctx.state, &owner)? {
+        let params =
+            read_pos_params::<_, namada_governance::Store<_>>(ctx.state)?;
+        return read_validator_stake(ctx.state, &params, &owner, epoch);
+    }
+    let delegations = find_delegations::<_, namada_governance::Store<_>>(
+        ctx.state, &owner, &epoch,
+    )?;
+    let mut total = VotePower::zero();
+    for amount in delegations.into_values() {
+        checked!(total += amount)?;
+    }
+    Ok(total)
+}
+
 /// Get the governance parameters
 fn parameters<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -61,3 +183,278 @@ where
 {
     namada_governance::storage::get_proposal_result(ctx.state, id)
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::{address, token};
+    use namada_state::{StorageRead, StorageWrite};
+
+    use super::*;
+    use crate::queries::RPC;
+    use crate::queries::testing::TestClient;
+
+    #[tokio::test]
+    async fn test_proposal_voting_power_query() {
+        let mut client = TestClient::new(RPC);
+        let genesis_validator =
+            namada_proof_of_stake::test_utils::get_dummy_genesis_validator();
+        let validator = genesis_validator.address.clone();
+        let validator_stake = genesis_validator.tokens;
+        let params = namada_proof_of_stake::test_utils::test_init_genesis::<
+            _,
+            namada_parameters::Store<_>,
+            namada_governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            namada_proof_of_stake::OwnedPosParams::default(),
+            std::iter::once(genesis_validator),
+            Epoch(0),
+        )
+        .expect("Test initialization failed");
+
+        // Delegate some tokens, which become active at the pipeline epoch
+        let delegator = address::testing::established_address_2();
+        let bond_amount = token::Amount::native_whole(100);
+        let native_token = client.state.get_native_token().unwrap();
+        StorageWrite::write(
+            &mut client.state,
+            &namada_token::storage_key::balance_key(&native_token, &delegator),
+            bond_amount,
+        )
+        .unwrap();
+        namada_proof_of_stake::bond_tokens::<
+            _,
+            namada_governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            Some(&delegator),
+            &validator,
+            bond_amount,
+            Epoch(0),
+            None,
+        )
+        .expect("Bonding tokens failed");
+
+        // One proposal tallied before the pipeline epoch and one after
+        let pipeline_epoch = Epoch(params.pipeline_len);
+        let (early, late) = (0_u64, 1_u64);
+        StorageWrite::write(
+            &mut client.state,
+            &gov_keys::get_voting_end_epoch_key(early),
+            pipeline_epoch.prev().unwrap(),
+        )
+        .unwrap();
+        StorageWrite::write(
+            &mut client.state,
+            &gov_keys::get_voting_end_epoch_key(late),
+            pipeline_epoch,
+        )
+        .unwrap();
+
+        let power = |addr: Address, id: u64| {
+            let client = &client;
+            async move {
+                crate::rpc::query_voting_power(client, &addr, id)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(
+            power(delegator.clone(), early).await,
+            token::Amount::zero()
+        );
+        assert_eq!(power(delegator, late).await, bond_amount);
+        assert_eq!(power(validator.clone(), early).await, validator_stake);
+        assert_eq!(
+            power(validator, late).await,
+            checked!(validator_stake + bond_amount).unwrap()
+        );
+
+        // An address without any stake has no voting power
+        let other = address::testing::established_address_3();
+        assert_eq!(power(other, late).await, token::Amount::zero());
+
+        // Unknown proposals are rejected
+        assert!(
+            crate::rpc::query_voting_power(
+                &client,
+                &address::testing::established_address_3(),
+                2
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proposal_view_query() {
+        use std::collections::BTreeMap;
+
+        use namada_core::borsh::BorshSerializeExt;
+        use namada_core::hash::Hash;
+        use namada_governance::storage::proposal::{
+            InitProposalData, ProposalType,
+        };
+        use namada_governance::storage::vote::ProposalVote;
+        use namada_governance::utils::TallyResult;
+
+        let mut client = TestClient::new(RPC);
+        let genesis_validator =
+            namada_proof_of_stake::test_utils::get_dummy_genesis_validator();
+        let validator = genesis_validator.address.clone();
+        let validator_stake = genesis_validator.tokens;
+        let params = namada_proof_of_stake::test_utils::test_init_genesis::<
+            _,
+            namada_parameters::Store<_>,
+            namada_governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            namada_proof_of_stake::OwnedPosParams::default(),
+            std::iter::once(genesis_validator),
+            Epoch(0),
+        )
+        .expect("Test initialization failed");
+        GovernanceParameters::default()
+            .init_storage(&mut client.state)
+            .unwrap();
+        let native_token = client.state.get_native_token().unwrap();
+
+        // Two delegators, one of them delegating twice
+        let delegator = address::testing::established_address_2();
+        let other_delegator = address::testing::established_address_3();
+        let bond_amount = token::Amount::native_whole(100);
+        let other_bond_amount = token::Amount::native_whole(30);
+        for (source, amount, bonds) in [
+            (&delegator, bond_amount, 2_u64),
+            (&other_delegator, other_bond_amount, 1),
+        ] {
+            namada_token::credit_tokens(
+                &mut client.state,
+                &native_token,
+                source,
+                token::Amount::native_whole(1_000),
+            )
+            .unwrap();
+            for _ in 0..bonds {
+                namada_proof_of_stake::bond_tokens::<
+                    _,
+                    namada_governance::Store<_>,
+                    namada_token::Store<_>,
+                >(
+                    &mut client.state,
+                    Some(source),
+                    &validator,
+                    amount,
+                    Epoch(0),
+                    None,
+                )
+                .expect("Bonding tokens failed");
+            }
+        }
+        let delegated = checked!(bond_amount + bond_amount).unwrap();
+
+        let author = address::testing::established_address_1();
+        namada_token::credit_tokens(
+            &mut client.state,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1_000),
+        )
+        .unwrap();
+        let content: BTreeMap<String, String> =
+            [("title".to_owned(), "A proposal".to_owned())].into();
+        // The votes are tallied after the bonds become active
+        let voting_end_epoch = Epoch(params.pipeline_len);
+        let data = InitProposalData {
+            content: Hash::default(),
+            author: author.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch,
+            activation_epoch: voting_end_epoch.next(),
+        };
+        let id = namada_governance::storage::init_proposal::<
+            _,
+            namada_token::Store<_>,
+        >(
+            &mut client.state, &data, content.serialize_to_vec(), None
+        )
+        .unwrap();
+
+        // The validator votes yay, which one of the delegators overrides with
+        // a nay and the other with an abstain vote
+        let votes = [
+            (validator.clone(), ProposalVote::Yay),
+            (delegator, ProposalVote::Nay),
+            (other_delegator, ProposalVote::Abstain),
+        ];
+        for (voter, vote) in votes {
+            StorageWrite::write(
+                &mut client.state,
+                &gov_keys::get_vote_proposal_key(id, voter, validator.clone()),
+                vote,
+            )
+            .unwrap();
+        }
+        client.state.commit_block().unwrap();
+
+        let view = crate::rpc::query_proposal(&client, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(view.proposal.id, id);
+        assert_eq!(view.proposal.author, author);
+        assert_eq!(view.proposal.content, content);
+        assert_eq!(view.proposal.voting_start_epoch, Epoch(0));
+        assert_eq!(view.proposal.voting_end_epoch, voting_end_epoch);
+        assert_eq!(view.status, ProposalStatus::OnGoing);
+        // The bonds of a delegator to the same validator are counted once
+        // with their total amount
+        assert_eq!(
+            (view.yay_power, view.nay_power, view.abstain_power),
+            (validator_stake, delegated, other_bond_amount)
+        );
+
+        // The stored result of an ended proposal is returned as is
+        let stored_result = ProposalResult {
+            result: TallyResult::Passed,
+            tally_type: TallyType::TwoFifths,
+            total_voting_power: token::Amount::native_whole(10),
+            total_yay_power: token::Amount::native_whole(3),
+            total_nay_power: token::Amount::native_whole(2),
+            total_abstain_power: token::Amount::native_whole(1),
+        };
+        StorageWrite::write(
+            &mut client.state,
+            &gov_keys::get_proposal_result_key(id),
+            stored_result,
+        )
+        .unwrap();
+        client.state.commit_block().unwrap();
+        client.state.in_mem_mut().last_epoch = voting_end_epoch.next();
+        let view = crate::rpc::query_proposal(&client, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(view.status, ProposalStatus::Ended);
+        assert_eq!(
+            (view.yay_power, view.nay_power, view.abstain_power),
+            (
+                stored_result.total_yay_power,
+                stored_result.total_nay_power,
+                stored_result.total_abstain_power
+            )
+        );
+
+        // An unknown proposal
+        assert!(
+            crate::rpc::query_proposal(&client, id + 1)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 // Re-export to show in rustdoc!
 use governance::GOV;
-pub use governance::Gov;
+pub use governance::{Gov, ProposalView};
 use pos::POS;
 pub use pos::Pos;
 use token::TOKEN;
@@ -7,11 +7,13 @@ use namada_core::address::Address;
 use namada_core::arith::{self, checked};
 use namada_core::chain::Epoch;
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::key::{common, tm_consensus_key_raw_hash};
 use namada_core::token;
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::queries::{
     find_delegation_validators, find_delegations,
+    find_validator_commission_rates,
 };
 use namada_proof_of_stake::rewards::read_rewards_counter;
 use namada_proof_of_stake::slashing::{
@@ -43,6 +45,10 @@ use crate::governance;
 use crate::queries::types::RequestCtx;
 use crate::queries::{RequestQuery, shell};
 
+/// The maximum number of epochs that can be requested in a single
+/// `rewards_range` query.
+pub const MAX_REWARDS_RANGE_EPOCHS: u64 = 100;
+
 // PoS validity predicate queries
 router! {POS,
     ( "validator" ) = {
@@ -64,6 +70,9 @@ router! {POS,
         ( "commission" / [validator: Address] / [epoch: opt Epoch] )
             -> CommissionPair = validator_commission,
 
+        ( "commission_history" / [validator: Address] )
+            -> BTreeMap<Epoch, Dec> = validator_commission_history,
+
         ( "metadata" / [validator: Address] )
             -> Option<ValidatorMetaData> = validator_metadata,
 
@@ -108,6 +117,9 @@ router! {POS,
     ( "rewards" / [validator: Address] / [source: opt Address] / [epoch: opt Epoch] )
         -> token::Amount = rewards,
 
+    ( "rewards_range" / [validator: Address] / [from_epoch: Epoch] / [to_epoch: Epoch] / [source: opt Address] )
+        -> Vec<(Epoch, token::Amount)> = rewards_range,
+
     ( "bond_with_slashing" / [source: Address] / [validator: Address] / [epoch: opt Epoch] )
         -> token::Amount = bond_with_slashing,
 
@@ -197,7 +209,8 @@ where
     read_pos_params::<_, governance::Store<_>>(ctx.state)
 }
 
-/// Find if the given address belongs to a validator account.
+/// Find if the given address belongs to a validator account, including
+/// inactive and jailed validators.
 fn is_validator<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     addr: Address,
@@ -315,6 +328,19 @@ where
     })
 }
 
+/// Get the commission rates of a validator kept in storage, keyed by the epoch
+/// from which each rate is in effect
+fn validator_commission_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<BTreeMap<Epoch, Dec>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    find_validator_commission_rates(ctx.state, &validator)
+}
+
 /// Get the validator metadata
 fn validator_metadata<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -627,6 +653,62 @@ where
     }
 }
 
+/// Get the rewards earned in each epoch in the inclusive range
+/// `from_epoch..=to_epoch`, i.e. the change of the rewards available to claim
+/// from the end of the previous epoch. Every epoch in the range is included in
+/// the returned series, with a zero amount for epochs in which no rewards were
+/// earned, so that the result always has one entry per epoch. If the rewards
+/// got claimed during an epoch, the rewards available as of that epoch are
+/// returned for it.
+fn rewards_range<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    from_epoch: Epoch,
+    to_epoch: Epoch,
+    source: Option<Address>,
+) -> namada_storage::Result<Vec<(Epoch, token::Amount)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let span = to_epoch
+        .checked_sub(from_epoch)
+        .ok_or_err_msg("The range end epoch must not precede its start")?;
+    if u64::from(span) >= MAX_REWARDS_RANGE_EPOCHS {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "The requested range spans more than {MAX_REWARDS_RANGE_EPOCHS} \
+             epochs"
+        )));
+    }
+
+    let rewards_at = |epoch| {
+        // The rewards query doesn't need the wasm caches, so we can get a
+        // fresh context for every epoch without requiring them to be `Clone`
+        let epoch_ctx = RequestCtx {
+            state: ctx.state,
+            event_log: ctx.event_log,
+            vp_wasm_cache: (),
+            tx_wasm_cache: (),
+            storage_read_past_height_limit: ctx.storage_read_past_height_limit,
+        };
+        rewards(epoch_ctx, validator.clone(), source.clone(), Some(epoch))
+    };
+
+    // No rewards are available before the first epoch
+    let mut prev_rewards = match from_epoch.prev() {
+        Some(prev_epoch) => rewards_at(prev_epoch)?,
+        None => token::Amount::zero(),
+    };
+    Epoch::iter_bounds_inclusive(from_epoch, to_epoch)
+        .map(|epoch| {
+            let rewards = rewards_at(epoch)?;
+            let earned = rewards.checked_sub(prev_rewards).unwrap_or(rewards);
+            prev_rewards = rewards;
+            Ok((epoch, earned))
+        })
+        .collect()
+}
+
 fn get_rewards_counter_at_epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: &Address,
@@ -715,7 +797,8 @@ where
     find_delegations::<_, governance::Store<_>>(ctx.state, &owner, &epoch)
 }
 
-/// Validator slashes
+/// Validator slashes, sorted by the epoch of the slashable event. A validator
+/// that has never been slashed has no slashes.
 fn validator_slashes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     validator: Address,
@@ -725,7 +808,11 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let slash_handle = validator_slashes_handle(&validator);
-    slash_handle.iter(ctx.state)?.collect()
+    let mut slashes: Vec<Slash> = slash_handle
+        .iter(ctx.state)?
+        .collect::<namada_storage::Result<_>>()?;
+    slashes.sort_by_key(|slash| slash.epoch);
+    Ok(slashes)
 }
 
 /// All slashes
@@ -902,6 +989,7 @@ fn enrich_bonds_and_unbonds(
 mod test {
     use namada_core::chain::Epoch;
     use namada_core::{address, token};
+    use namada_proof_of_stake::types::{SlashType, ValidatorState};
     use namada_state::StorageWrite;
 
     use super::*;
@@ -1303,4 +1391,345 @@ mod test {
             .expect("Rewards query failed");
         assert_eq!(result, val_reward_epoch_3 + val_reward_epoch_2);
     }
+
+    #[tokio::test]
+    async fn test_is_validator_query() {
+        let mut client = TestClient::new(RPC);
+        let (validator, params) = helpers::init_validator(&mut client);
+        let non_validator = address::testing::established_address_2();
+
+        // An active validator
+        assert!(crate::rpc::is_validator(&client, &validator).await.unwrap());
+
+        // A jailed validator is still a validator
+        namada_proof_of_stake::jail_validator::<_, governance::Store<_>>(
+            &mut client.state,
+            &params,
+            &validator,
+            Epoch(0),
+            Epoch(0),
+        )
+        .expect("Jailing the validator failed");
+        let (state, _epoch) = RPC
+            .vp()
+            .pos()
+            .validator_state(&client, &validator, &Some(Epoch(0)))
+            .await
+            .unwrap();
+        assert_eq!(state, Some(ValidatorState::Jailed));
+        assert!(crate::rpc::is_validator(&client, &validator).await.unwrap());
+
+        // An ordinary account
+        assert!(
+            !crate::rpc::is_validator(&client, &non_validator)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commission_rate_query() {
+        let mut client = TestClient::new(RPC);
+        let (validator, params) = helpers::init_validator(&mut client);
+        let non_validator = address::testing::established_address_2();
+        let genesis_rate = Dec::new(1, 1).unwrap();
+        let new_rate = Dec::new(15, 2).unwrap();
+
+        // Change the rate at epoch 0, effective at the pipeline epoch
+        namada_proof_of_stake::change_validator_commission_rate::<
+            _,
+            governance::Store<_>,
+        >(&mut client.state, &validator, new_rate, Epoch(0))
+        .expect("Changing the commission rate failed");
+        let pipeline_epoch = Epoch(params.pipeline_len);
+
+        for epoch in 0..params.pipeline_len {
+            let pair = crate::rpc::query_commission_rate(
+                &client,
+                &validator,
+                Some(Epoch(epoch)),
+            )
+            .await
+            .unwrap();
+            assert_eq!(pair.commission_rate, Some(genesis_rate));
+        }
+        for epoch in [pipeline_epoch, pipeline_epoch.next()] {
+            let pair = crate::rpc::query_commission_rate(
+                &client,
+                &validator,
+                Some(epoch),
+            )
+            .await
+            .unwrap();
+            assert_eq!(pair.commission_rate, Some(new_rate));
+        }
+
+        let history =
+            crate::rpc::query_commission_rate_history(&client, &validator)
+                .await
+                .unwrap();
+        assert_eq!(
+            history,
+            BTreeMap::from([
+                (Epoch(0), genesis_rate),
+                (pipeline_epoch, new_rate)
+            ])
+        );
+
+        // An address that has never been a validator has no rate
+        let pair = crate::rpc::query_commission_rate(
+            &client,
+            &non_validator,
+            Some(Epoch(0)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pair.commission_rate, None);
+        assert!(
+            crate::rpc::query_commission_rate_history(&client, &non_validator)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validator_slashes_query() {
+        let mut client = TestClient::new(RPC);
+        let (validator, _params) = helpers::init_validator(&mut client);
+
+        // A validator that has never been slashed
+        assert!(
+            crate::rpc::query_slashes(&client, &validator)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Seed two slashes, the later one first
+        let later = Slash {
+            epoch: Epoch(5),
+            block_height: 50,
+            r#type: SlashType::LightClientAttack,
+            rate: Dec::new(1, 2).unwrap(),
+        };
+        let earlier = Slash {
+            epoch: Epoch(2),
+            block_height: 20,
+            r#type: SlashType::DuplicateVote,
+            rate: Dec::new(5, 2).unwrap(),
+        };
+        let slash_handle = validator_slashes_handle(&validator);
+        slash_handle.push(&mut client.state, later.clone()).unwrap();
+        slash_handle
+            .push(&mut client.state, earlier.clone())
+            .unwrap();
+
+        let slashes = crate::rpc::query_slashes(&client, &validator)
+            .await
+            .unwrap();
+        assert_eq!(slashes, vec![earlier, later]);
+    }
+
+    #[tokio::test]
+    async fn test_pos_params_query() {
+        let mut client = TestClient::new(RPC);
+        let params = namada_proof_of_stake::OwnedPosParams {
+            max_validator_slots: 42,
+            pipeline_len: 3,
+            unbonding_len: 7,
+            cubic_slashing_window_length: 2,
+            ..Default::default()
+        };
+        namada_proof_of_stake::test_utils::test_init_genesis::<
+            _,
+            namada_parameters::Store<_>,
+            governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            params,
+            std::iter::once(
+                namada_proof_of_stake::test_utils::get_dummy_genesis_validator(
+                ),
+            ),
+            Epoch(0),
+        )
+        .expect("Test initialization failed");
+
+        let params = crate::rpc::get_pos_params(&client).await.unwrap();
+        assert_eq!(params.unbonding_len, 7);
+        assert_eq!(params.pipeline_len, 3);
+        assert_eq!(params.cubic_slashing_window_length, 2);
+        assert_eq!(params.max_validator_slots, 42);
+    }
+
+    #[tokio::test]
+    async fn test_total_stake_query() {
+        let mut client = TestClient::new(RPC);
+        let (validator, params) = helpers::init_validator(&mut client);
+        let bond_amount = token::Amount::native_whole(100);
+        let unbond_amount = token::Amount::native_whole(30);
+        // Bonds and unbonds take effect at the pipeline offset
+        let effective_epoch = Epoch(params.pipeline_len);
+
+        let genesis_stake =
+            crate::rpc::get_total_staked_tokens(&client, Epoch(0))
+                .await
+                .unwrap();
+        assert!(!genesis_stake.is_zero());
+        assert_eq!(
+            crate::rpc::get_total_staked_tokens(&client, effective_epoch)
+                .await
+                .unwrap(),
+            genesis_stake
+        );
+
+        // Bonding increases the total stake
+        let delegator = address::testing::established_address_2();
+        let native_token = client.state.get_native_token().unwrap();
+        StorageWrite::write(
+            &mut client.state,
+            &namada_token::storage_key::balance_key(&native_token, &delegator),
+            bond_amount,
+        )
+        .expect("Credit tokens failed");
+        namada_proof_of_stake::bond_tokens::<
+            _,
+            governance::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut client.state,
+            Some(&delegator),
+            &validator,
+            bond_amount,
+            Epoch(0),
+            None,
+        )
+        .expect("Bonding tokens failed");
+        assert_eq!(
+            crate::rpc::get_total_staked_tokens(&client, effective_epoch)
+                .await
+                .unwrap(),
+            genesis_stake.checked_add(bond_amount).unwrap()
+        );
+
+        // Unbonding decreases it
+        namada_proof_of_stake::unbond_tokens::<_, governance::Store<_>>(
+            &mut client.state,
+            Some(&delegator),
+            &validator,
+            unbond_amount,
+            Epoch(0),
+            false,
+        )
+        .expect("Unbonding tokens failed");
+        assert_eq!(
+            crate::rpc::get_total_staked_tokens(&client, effective_epoch)
+                .await
+                .unwrap(),
+            genesis_stake
+                .checked_add(bond_amount)
+                .and_then(|stake| stake.checked_sub(unbond_amount))
+                .unwrap()
+        );
+
+        // Epochs before the pipeline offset are unaffected
+        assert_eq!(
+            crate::rpc::get_total_staked_tokens(
+                &client,
+                effective_epoch.prev().unwrap()
+            )
+            .await
+            .unwrap(),
+            genesis_stake
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewards_range_query() {
+        let mut client = TestClient::new(RPC);
+        let pos = RPC.vp().pos();
+
+        let (validator, delegator, _bond_amount) =
+            helpers::init_state(&mut client, token::Amount::native_whole(100));
+        let bond = (validator.clone(), delegator.clone());
+
+        // Seed rewards across three epochs, the first of which has none
+        let del_reward_epoch_2 = token::Amount::native_whole(7);
+        let del_reward_epoch_3 = token::Amount::native_whole(11);
+        helpers::advance_epoch(&mut client, &bond, &(None, None));
+        helpers::advance_epoch(
+            &mut client,
+            &bond,
+            &(None, Some(del_reward_epoch_2)),
+        );
+        let epoch = helpers::advance_epoch(
+            &mut client,
+            &bond,
+            &(None, Some(del_reward_epoch_3)),
+        );
+        assert_eq!(epoch, Epoch(3));
+
+        let result = pos
+            .rewards_range(
+                &client,
+                &validator,
+                &Epoch(1),
+                &Epoch(3),
+                &Some(delegator.clone()),
+            )
+            .await
+            .expect("Rewards range query failed");
+        assert_eq!(
+            result,
+            vec![
+                (Epoch(1), token::Amount::zero()),
+                (Epoch(2), del_reward_epoch_2),
+                (Epoch(3), del_reward_epoch_3),
+            ]
+        );
+
+        // The deltas of a range starting later are relative to the rewards of
+        // the epoch before it
+        let result = pos
+            .rewards_range(
+                &client,
+                &validator,
+                &Epoch(3),
+                &Epoch(3),
+                &Some(delegator.clone()),
+            )
+            .await
+            .expect("Rewards range query failed");
+        assert_eq!(result, vec![(Epoch(3), del_reward_epoch_3)]);
+
+        // The validator itself received no rewards
+        let result = pos
+            .rewards_range(&client, &validator, &Epoch(1), &Epoch(3), &None)
+            .await
+            .expect("Rewards range query failed");
+        assert!(result.iter().all(|(_, amount)| amount.is_zero()));
+        assert_eq!(result.len(), 3);
+
+        // Inverted range
+        assert!(
+            pos.rewards_range(&client, &validator, &Epoch(3), &Epoch(1), &None)
+                .await
+                .is_err()
+        );
+
+        // Range that is too long
+        assert!(
+            pos.rewards_range(
+                &client,
+                &validator,
+                &Epoch(0),
+                &Epoch(MAX_REWARDS_RANGE_EPOCHS),
+                &None
+            )
+            .await
+            .is_err()
+        );
+    }
 }
@@ -1,20 +1,25 @@
 //! Token validity predicate queries
 
+use std::collections::BTreeSet;
+
 use namada_core::address::Address;
 use namada_core::token;
 use namada_proof_of_stake::rewards::{
     PosRewardsRates, estimate_staking_reward_rate,
 };
-use namada_state::{DB, DBIter, StorageHasher};
+use namada_state::{DB, DBIter, StorageHasher, StorageRead};
 use namada_token::{
-    get_effective_total_native_supply, read_denom, read_total_supply,
+    get_effective_total_native_supply, read_balances, read_denom,
+    read_metadata, read_token_map, read_total_supply,
 };
 
 use crate::queries::RequestCtx;
 
 router! {TOKEN,
     ( "denomination" / [token: Address] ) -> Option<token::Denomination> = denomination,
+    ( "metadata" / [token: Address] ) -> Option<token::TokenMetadata> = metadata,
     ( "total_supply" / [token: Address] ) -> token::Amount = total_supply,
+    ( "known_tokens_portfolio" / [owner: Address] ) -> Vec<(Address, token::Amount)> = known_tokens_portfolio,
     ( "effective_native_supply" ) -> token::Amount = effective_native_supply,
     ( "staking_rewards_rate" ) -> PosRewardsRates = staking_rewards_rate,
 }
@@ -32,6 +37,18 @@ where
     read_denom(ctx.state, &token)
 }
 
+/// Get the metadata of a token, if any
+fn metadata<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+) -> namada_storage::Result<Option<token::TokenMetadata>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_metadata(ctx.state, &token)
+}
+
 /// Get the total supply for a token address
 fn total_supply<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -44,6 +61,28 @@ where
     read_total_supply(ctx.state, &token)
 }
 
+/// Get the non-zero balances of the given owner in the known tokens, i.e. the
+/// native token and the tokens of the MASP token map, sorted by the token
+/// address. Balances in any other token are not included: only the balance
+/// keys of the known tokens are read, so that the query doesn't depend on the
+/// size of the multitoken storage.
+fn known_tokens_portfolio<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<Vec<(Address, token::Amount)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut tokens = read_token_map(ctx.state)?
+        .into_values()
+        .collect::<BTreeSet<_>>();
+    tokens.insert(ctx.state.get_native_token()?);
+    Ok(read_balances(ctx.state, &owner, &tokens)?
+        .into_iter()
+        .collect())
+}
+
 /// Get the effective total supply of the native token
 fn effective_native_supply<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -135,3 +174,107 @@ pub mod client_only_methods {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::address;
+    use namada_core::masp::TokenMap;
+    use namada_core::token::{self, TokenMetadata};
+    use namada_state::StorageWrite;
+    use namada_token::storage_key::balance_key;
+    use namada_token::{write_metadata, write_token_map};
+
+    use crate::queries::RPC;
+    use crate::queries::testing::TestClient;
+
+    #[tokio::test]
+    async fn test_token_metadata_query() {
+        let mut client = TestClient::new(RPC);
+        let token = address::testing::established_address_1();
+        let other_token = address::testing::established_address_2();
+        let metadata = TokenMetadata {
+            name: "Bitcoin".to_string(),
+            symbol: "BTC".to_string(),
+            decimals: 8.into(),
+        };
+        write_metadata(&mut client.state, &token, &metadata).unwrap();
+        client.state.commit_block().unwrap();
+
+        let queried = crate::rpc::query_token_metadata(&client, &token)
+            .await
+            .unwrap();
+        assert_eq!(queried, Some(metadata));
+
+        // A token without metadata
+        let queried = crate::rpc::query_token_metadata(&client, &other_token)
+            .await
+            .unwrap();
+        assert_eq!(queried, None);
+    }
+
+    #[tokio::test]
+    async fn test_known_tokens_portfolio_query() {
+        let mut client = TestClient::new(RPC);
+        let native_token = client.state.in_mem().native_token.clone();
+        let token_a = address::testing::established_address_1();
+        let token_b = address::testing::established_address_2();
+        let owner = address::testing::established_address_3();
+        let other_owner = address::testing::gen_implicit_address();
+
+        let balances = [
+            (&native_token, &owner, token::Amount::native_whole(10)),
+            (&token_a, &owner, token::Amount::from_u64(1)),
+            (&token_b, &owner, token::Amount::from_u64(2)),
+            (&token_a, &other_owner, token::Amount::from_u64(3)),
+        ];
+        for (token, owner, amount) in balances {
+            client
+                .state
+                .write(&balance_key(token, owner), amount)
+                .unwrap();
+        }
+        // A zero balance is not a part of the portfolio
+        let token_c = address::testing::gen_established_address();
+        client
+            .state
+            .write(&balance_key(&token_c, &owner), token::Amount::zero())
+            .unwrap();
+        // A token outside of the known tokens is not a part of the portfolio
+        let unknown_token = address::testing::established_address_4();
+        client
+            .state
+            .write(
+                &balance_key(&unknown_token, &owner),
+                token::Amount::from_u64(4),
+            )
+            .unwrap();
+        let token_map: TokenMap =
+            [("a", &token_a), ("b", &token_b), ("c", &token_c)]
+                .into_iter()
+                .map(|(alias, token)| (alias.to_string(), token.clone()))
+                .collect();
+        write_token_map(&mut client.state, token_map).unwrap();
+        client.state.commit_block().unwrap();
+
+        let portfolio =
+            crate::rpc::query_known_tokens_portfolio(&client, &owner)
+                .await
+                .unwrap();
+        let mut expected = vec![
+            (native_token, token::Amount::native_whole(10)),
+            (token_a, token::Amount::from_u64(1)),
+            (token_b, token::Amount::from_u64(2)),
+        ];
+        expected.sort();
+        assert_eq!(portfolio, expected);
+
+        // An owner without any balances
+        let portfolio = crate::rpc::query_known_tokens_portfolio(
+            &client,
+            &address::testing::established_address_5(),
+        )
+        .await
+        .unwrap();
+        assert!(portfolio.is_empty());
+    }
+}
@@ -16,10 +16,13 @@ use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
 use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::ibc::IbcTokenHash;
 use namada_core::ibc::apps::transfer::types::PrefixedDenom;
-use namada_core::ibc::core::host::types::identifiers::ChannelId;
+use namada_core::ibc::core::host::types::identifiers::{
+    ChannelId, PortId, Sequence,
+};
 use namada_core::key::common;
 use namada_core::masp::MaspEpoch;
 use namada_core::storage::{BlockResults, Key, PrefixValue};
@@ -51,10 +54,10 @@ use namada_parameters::{EpochDuration, storage as params_storage};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::rewards::PosRewardsRates;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, LivenessInfo, ValidatorMetaData,
-    WeightedValidator,
+    BondsAndUnbondsDetails, CommissionPair, LivenessInfo, Slash,
+    ValidatorMetaData, WeightedValidator,
 };
-use namada_state::{BlockHeader, LastBlock};
+use namada_state::{BlockHeader, LastBlock, StoreType};
 use namada_token::masp::MaspTokenRewardData;
 use namada_tx::data::{BatchedTxResult, DryRunResult, ResultCode, TxResult};
 use namada_tx::event::{Batch as BatchAttr, Code as CodeAttr};
@@ -65,10 +68,11 @@ use crate::control_flow::time;
 use crate::error::{EncodingError, Error, QueryError, TxSubmitError};
 use crate::events::{Event, extend};
 use crate::internal_macros::echo_error;
-use crate::queries::RPC;
+use crate::queries::vp::ProposalView;
 use crate::queries::vp::pos::{
     EnrichedBondsAndUnbondsDetails, ValidatorStateInfo,
 };
+use crate::queries::{EpochInfo, GenesisInfo, RPC};
 use crate::tendermint::block::Height;
 use crate::tendermint::merkle::proof::ProofOps;
 use crate::tendermint_rpc::query::Query;
@@ -168,6 +172,26 @@ pub async fn query_block_header<C: namada_io::Client + Sync>(
     convert_response::<C, _>(RPC.shell().block_header(client, &height).await)
 }
 
+/// Query the hashes of the transactions applied at the given height, in the
+/// order in which they were applied. Defaults to the last committed block.
+pub async fn query_block_txs<C: namada_io::Client + Sync>(
+    client: &C,
+    height: Option<BlockHeight>,
+) -> Result<Vec<Hash>, error::Error> {
+    convert_response::<C, _>(RPC.shell().block_txs(client, &height).await)
+}
+
+/// Query the events emitted by the transaction with the given hash, in the
+/// order in which they are kept in the node's event log. Only the events that
+/// are still retained by the event log are returned, so the events of an old
+/// transaction may have been pruned. Returns no events for an unknown hash.
+pub async fn query_events_for_tx<C: namada_io::Client + Sync>(
+    client: &C,
+    tx_hash: Hash,
+) -> Result<Vec<Event>, error::Error> {
+    convert_response::<C, _>(RPC.shell().tx_events(client, &tx_hash).await)
+}
+
 /// Query the masp epoch of the last committed block
 pub async fn query_masp_epoch<C: namada_io::Client + Sync>(
     client: &C,
@@ -182,6 +206,27 @@ pub async fn query_native_token<C: namada_io::Client + Sync>(
     convert_response::<C, _>(RPC.shell().native_token(client).await)
 }
 
+/// Query the commitments of the IBC packets sent on a channel that still await
+/// an acknowledgement or a timeout, ordered by sequence
+pub async fn query_pending_packets<C: namada_io::Client + Sync>(
+    client: &C,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Vec<(Sequence, Vec<u8>)>, error::Error> {
+    convert_response::<C, _>(
+        RPC.shell()
+            .ibc_pending_packets(client, port_id, channel_id)
+            .await,
+    )
+}
+
+/// Query the current protocol parameters.
+pub async fn query_parameters<C: namada_io::Client + Sync>(
+    client: &C,
+) -> Result<namada_parameters::Parameters, error::Error> {
+    convert_response::<C, _>(RPC.shell().parameters(client).await)
+}
+
 /// Query the epoch of the given block height, if it exists.
 /// Will return none if the input block height is greater than
 /// the latest committed block height.
@@ -192,6 +237,21 @@ pub async fn query_epoch_at_height<C: namada_io::Client + Sync>(
     convert_response::<C, _>(RPC.shell().epoch_at_height(client, &height).await)
 }
 
+/// Query the boundaries of the given epoch, if it's known.
+pub async fn query_epoch_info<C: namada_io::Client + Sync>(
+    client: &C,
+    epoch: Epoch,
+) -> Result<Option<EpochInfo>, error::Error> {
+    convert_response::<C, _>(RPC.shell().epoch_info(client, &epoch).await)
+}
+
+/// Query the genesis configuration of the chain that is available on-chain.
+pub async fn query_genesis_info<C: namada_io::Client + Sync>(
+    client: &C,
+) -> Result<GenesisInfo, error::Error> {
+    convert_response::<C, _>(RPC.shell().genesis_info(client).await)
+}
+
 /// Query the last committed block, if any.
 pub async fn query_block<C: namada_io::Client + Sync>(
     client: &C,
@@ -201,6 +261,14 @@ pub async fn query_block<C: namada_io::Client + Sync>(
     convert_response::<C, _>(RPC.shell().last_block(client).await)
 }
 
+/// Query the merkle root of the given sub-tree at the last committed height.
+pub async fn query_store_root<C: namada_io::Client + Sync>(
+    client: &C,
+    store: StoreType,
+) -> Result<Hash, error::Error> {
+    convert_response::<C, _>(RPC.shell().store_root(client, &store).await)
+}
+
 /// A helper to unwrap client's response. Will shut down process on error.
 fn unwrap_client_response<C: namada_io::Client, T>(
     response: Result<T, C::Error>,
@@ -915,6 +983,23 @@ pub async fn query_rewards<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query and return the reward tokens earned by the bond in each epoch in the
+/// inclusive range `from_epoch..=to_epoch`
+pub async fn query_rewards_range<C: namada_io::Client + Sync>(
+    client: &C,
+    source: &Option<Address>,
+    validator: &Address,
+    from_epoch: Epoch,
+    to_epoch: Epoch,
+) -> Result<Vec<(Epoch, token::Amount)>, error::Error> {
+    convert_response::<C, Vec<(Epoch, token::Amount)>>(
+        RPC.vp()
+            .pos()
+            .rewards_range(client, validator, &from_epoch, &to_epoch, source)
+            .await,
+    )
+}
+
 /// Get the validators to which a delegator is bonded at a certain epoch
 pub async fn get_delegation_validators<C: namada_io::Client + Sync>(
     client: &C,
@@ -954,6 +1039,17 @@ pub async fn query_proposal_by_id<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query a proposal by its id together with its current status and the
+/// voting power of each kind of vote, in a single request
+pub async fn query_proposal<C: namada_io::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Option<ProposalView>, Error> {
+    convert_response::<C, _>(
+        RPC.vp().gov().proposal_view(client, &proposal_id).await,
+    )
+}
+
 /// Query and return validator's commission rate and max commission rate change
 /// per epoch
 pub async fn query_commission_rate<C: namada_io::Client + Sync>(
@@ -969,6 +1065,31 @@ pub async fn query_commission_rate<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query and return the commission rates of a validator kept in storage, keyed
+/// by the epoch from which each rate is in effect
+pub async fn query_commission_rate_history<C: namada_io::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<BTreeMap<Epoch, Dec>, Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_commission_history(client, validator)
+            .await,
+    )
+}
+
+/// Query and return the slashes of a validator, sorted by the epoch of the
+/// slashable event
+pub async fn query_slashes<C: namada_io::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<Vec<Slash>, Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().validator_slashes(client, validator).await,
+    )
+}
+
 /// Query and return validator's metadata, including the commission rate and max
 /// commission rate change
 pub async fn query_metadata<C: namada_io::Client + Sync>(
@@ -1038,6 +1159,15 @@ pub async fn get_account_info<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query the public keys controlling an account, ordered by their index, and
+/// the account's signature threshold
+pub async fn query_account_keys<C: namada_io::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<(Vec<common::PublicKey>, u8), error::Error> {
+    convert_response::<C, _>(RPC.shell().account_keys(client, owner).await)
+}
+
 /// Query if the public_key is revealed
 pub async fn is_public_key_revealed<C: namada_io::Client + Sync>(
     client: &C,
@@ -1245,6 +1375,21 @@ pub async fn query_proposal_votes<C: namada_io::Client + Sync>(
     )
 }
 
+/// Get the voting power of an address on a proposal, at the epoch in which
+/// the proposal's votes are tallied
+pub async fn query_voting_power<C: namada_io::Client + Sync>(
+    client: &C,
+    address: &Address,
+    proposal_id: u64,
+) -> Result<token::Amount, error::Error> {
+    convert_response::<C, token::Amount>(
+        RPC.vp()
+            .gov()
+            .proposal_voting_power(client, &proposal_id, address)
+            .await,
+    )
+}
+
 /// Query the information to estimate next epoch start
 pub async fn query_next_epoch_info<C: namada_io::Client + Sync>(
     client: &C,
@@ -1262,6 +1407,23 @@ pub async fn query_next_epoch_info<C: namada_io::Client + Sync>(
     Ok((this_epoch_first_height, epoch_duration))
 }
 
+/// Query the protocol parameters scheduled to take effect at a future epoch
+/// together with their activation epoch, if any.
+pub async fn query_pending_parameters<C: namada_io::Client + Sync>(
+    client: &C,
+) -> Result<Option<(Epoch, namada_parameters::Parameters)>, error::Error> {
+    let key = params_storage::get_pending_parameters_key();
+    let (value, _proof) =
+        query_storage_value_bytes(client, &key, None, false).await?;
+    value
+        .map(|bytes| {
+            BorshDeserialize::try_from_slice(&bytes[..]).map_err(|err| {
+                Error::from(EncodingError::Decoding(err.to_string()))
+            })
+        })
+        .transpose()
+}
+
 /// Get the bond amount at the given epoch
 pub async fn get_bond_amount_at<C: namada_io::Client + Sync>(
     client: &C,
@@ -1325,6 +1487,27 @@ pub async fn query_denom<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query the metadata of a token, if any
+pub async fn query_token_metadata<C: namada_io::Client + Sync>(
+    client: &C,
+    token: &Address,
+) -> Result<Option<token::TokenMetadata>, error::Error> {
+    convert_response::<C, _>(RPC.vp().token().metadata(client, token).await)
+}
+
+/// Query the non-zero balances of the given owner in the known tokens, i.e.
+/// the native token and the tokens of the MASP token map, sorted by the token
+/// address. Balances in any other token, e.g. an IBC token that isn't in the
+/// MASP token map, are not included.
+pub async fn query_known_tokens_portfolio<C: namada_io::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Vec<(Address, token::Amount)>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().token().known_tokens_portfolio(client, owner).await,
+    )
+}
+
 /// Get the correct representation of the amount given the token type.
 pub async fn validate_amount<N: Namada>(
     context: &N,
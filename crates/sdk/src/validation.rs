@@ -56,6 +56,15 @@ pub type ParametersVp<'ctx, CTX> = parameters::vp::ParametersVp<
     governance::Store<<CTX as VpEnv<'ctx>>::Pre>,
 >;
 
+/// Native write rate-limiting VP
+pub type WriteRateLimitVp<'a, S, CA> =
+    native_vp::write_rate_limit::WriteRateLimitVp<
+        'a,
+        S,
+        VpCache<CA>,
+        Eval<S, CA>,
+    >;
+
 /// Native governance VP
 pub type GovernanceVp<'ctx, CTX> = governance::vp::GovernanceVp<
     'ctx,
@@ -23,6 +23,7 @@ pub mod prefix_iter;
 mod wl_state;
 pub mod write_log;
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::iter::Peekable;
 
@@ -37,12 +38,15 @@ pub use namada_core::chain::{
     BLOCK_HASH_LENGTH, BLOCK_HEIGHT_LENGTH, BlockHash, BlockHeader,
     BlockHeight, Epoch, Epochs,
 };
-use namada_core::eth_bridge_pool::is_pending_transfer_key;
+use namada_core::eth_bridge_pool::{
+    self, BRIDGE_POOL_ADDRESS, is_pending_transfer_key,
+};
 use namada_core::hash::Hash;
 pub use namada_core::hash::Sha256Hasher;
 pub use namada_core::storage::{
     BlockResults, EPOCH_TYPE_LENGTH, EthEventsQueue, Key, KeySeg, TxIndex,
 };
+use namada_core::storage::{KEY_SEGMENT_SEPARATOR, RESERVED_ADDRESS_PREFIX};
 use namada_core::tendermint::merkle::proof::ProofOps;
 use namada_gas::{
     Gas, MEMORY_ACCESS_GAS_PER_BYTE, STORAGE_ACCESS_GAS_PER_BYTE,
@@ -66,7 +70,7 @@ pub use namada_storage::{
 use namada_systems::parameters;
 use thiserror::Error;
 use wl_state::TxWlState;
-pub use wl_state::{FullAccessState, TempWlState, WlState};
+pub use wl_state::{CommitStats, FullAccessState, TempWlState, WlState};
 use write_log::WriteLog;
 
 /// We delay epoch change 2 blocks to keep it in sync with Tendermint, because
@@ -120,6 +124,17 @@ pub trait StateRead: StorageRead + Debug {
         }
     }
 
+    /// Read a value as it was last committed to DB, ignoring any changes in
+    /// the write log, and charge the gas cost.
+    fn read_bytes_committed(
+        &self,
+        key: &storage::Key,
+    ) -> Result<Option<Vec<u8>>> {
+        let (value, gas) = self.db_read(key)?;
+        self.charge_gas(gas)?;
+        Ok(value)
+    }
+
     /// WARNING: This only works for values that have been committed to DB.
     /// To be able to see values written or deleted, but not yet committed,
     /// use the `StorageWithWriteLog`.
@@ -136,6 +151,76 @@ pub trait StateRead: StorageRead + Debug {
         ))
     }
 
+    /// Classify every key modified in the write log, including the current
+    /// block, batch and transaction, against the committed storage. The
+    /// changes are sorted by their storage key.
+    fn pending_changes(
+        &self,
+    ) -> Result<Vec<(storage::Key, write_log::ChangeKind)>> {
+        let write_log = self.write_log();
+        let mut changes = vec![];
+        for key in write_log.get_block_keys() {
+            let (modification, gas) = write_log.read(key)?;
+            self.charge_gas(gas)?;
+            let Some(modification) = modification else {
+                continue;
+            };
+            let (committed, gas) = self.db_read(key)?;
+            self.charge_gas(gas)?;
+            let kind = write_log::ChangeKind::classify(
+                modification,
+                committed.as_deref(),
+            );
+            changes.push((key.clone(), kind));
+        }
+        Ok(changes)
+    }
+
+    /// Iterate storage items posterior to a tx execution, matching the given
+    /// prefix, that belong to the given merkle sub-tree `store`. Only the
+    /// parts of the prefix that intersect the sub-tree's key prefixes are
+    /// iterated. Keys under the same prefix may still be committed to
+    /// different sub-trees, e.g. the pending transfers of the Ethereum bridge
+    /// pool, and the gas of these is charged too.
+    fn iter_prefix_in_store(
+        &self,
+        store: StoreType,
+        prefix: &storage::Key,
+    ) -> Result<StorePrefixIter<'_, Self>>
+    where
+        Self: Sized,
+    {
+        let mut prefixes: Vec<storage::Key> = store
+            .key_prefixes()
+            .into_iter()
+            .filter_map(|store_prefix| {
+                if prefix.segments.starts_with(&store_prefix.segments) {
+                    Some(prefix.clone())
+                } else if store_prefix.segments.starts_with(&prefix.segments) {
+                    Some(store_prefix)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        prefixes.sort_by_cached_key(ToString::to_string);
+        prefixes.dedup();
+        let iters = prefixes
+            .iter()
+            .map(|prefix| {
+                let (iter, gas) =
+                    iter_prefix_post(self.write_log(), self.db(), prefix)?;
+                self.charge_gas(gas)?;
+                Ok(iter)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(StorePrefixIter {
+            state: self,
+            filter: RawStoreFilter::new(store),
+            iter: iters.into_iter().flatten(),
+        })
+    }
+
     /// Returns an iterator over the block results
     fn db_iter_results(&self) -> (<Self::D as DBIter<'_>>::PrefixIter, Gas) {
         (self.db().iter_results(), Gas::default())
@@ -235,6 +320,7 @@ macro_rules! impl_storage_read {
                 // try to read from the write log first
                 let (log_val, gas) = self.write_log().read(key)?;
                 self.charge_gas(gas).into_storage_result()?;
+                self.write_log().record_read(log_val.is_some());
                 match log_val {
                     Some(write_log::StorageModification::Write { value }) => {
                         Ok(Some(value.clone()))
@@ -292,6 +378,30 @@ macro_rules! impl_storage_read {
                 }).transpose()
             }
 
+            fn read_last_modified_height(
+                &self,
+                key: &storage::Key,
+            ) -> namada_storage::Result<Option<BlockHeight>> {
+                // a value modified in the write log is reported with the
+                // current height
+                let (log_val, gas) = self.write_log().read(key)?;
+                self.charge_gas(gas).into_storage_result()?;
+                match log_val {
+                    Some(&write_log::StorageModification::Write { .. })
+                    | Some(&write_log::StorageModification::InitAccount { .. }) => {
+                        return Ok(Some(self.in_mem().block.height));
+                    }
+                    Some(&write_log::StorageModification::Delete) => return Ok(None),
+                    None => {}
+                }
+                let (present, gas) = self.db_has_key(key).into_storage_result()?;
+                self.charge_gas(gas).into_storage_result()?;
+                if !present {
+                    return Ok(None);
+                }
+                last_committed_write_height(self, key)
+            }
+
             fn get_chain_id(
                 &self,
             ) -> std::result::Result<ChainId, namada_storage::Error> {
@@ -447,6 +557,40 @@ impl From<StateError> for Error {
     }
 }
 
+/// Find the last committed block in which the key was written, from the diffs
+/// of the blocks since the oldest stored epoch. Gas is charged for every
+/// scanned block height. Returns `None` if no diff of the key is found, which
+/// is the case for keys whose diffs are not persisted.
+fn last_committed_write_height<S>(
+    state: &S,
+    key: &storage::Key,
+) -> Result<Option<BlockHeight>>
+where
+    S: StateRead + ?Sized,
+{
+    let oldest_height = state
+        .in_mem()
+        .block
+        .pred_epochs
+        .first_block_heights()
+        .first()
+        .copied()
+        .unwrap_or_default();
+    let mut height = state.in_mem().get_last_block_height();
+    loop {
+        let len = key.len() as u64;
+        state
+            .charge_gas(checked!(len * STORAGE_ACCESS_GAS_PER_BYTE)?.into())?;
+        if state.db().read_diffs_val(key, height, false)?.is_some() {
+            return Ok(Some(height));
+        }
+        match height.prev_height() {
+            Some(prev) if prev >= oldest_height => height = prev,
+            _ => return Ok(None),
+        }
+    }
+}
+
 /// Prefix iterator for [`StorageRead`] implementations.
 #[derive(Debug)]
 pub struct PrefixIter<'iter, D>
@@ -509,6 +653,95 @@ where
     ))
 }
 
+/// Prefix iterator confined to the keys of a single merkle sub-tree. Gas is
+/// charged for every scanned item, including the items of other sub-trees
+/// that share the sub-tree's key prefixes.
+#[derive(Debug)]
+pub struct StorePrefixIter<'iter, S>
+where
+    S: StateRead,
+{
+    state: &'iter S,
+    filter: RawStoreFilter,
+    iter: std::iter::Flatten<std::vec::IntoIter<PrefixIter<'iter, S::D>>>,
+}
+
+impl<S> Iterator for StorePrefixIter<'_, S>
+where
+    S: StateRead,
+{
+    type Item = Result<(String, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, val, gas) in self.iter.by_ref() {
+            if let Err(err) = self.state.charge_gas(gas) {
+                return Some(Err(err));
+            }
+            if self.filter.contains(&key) {
+                return Some(Ok((key, val)));
+            }
+        }
+        None
+    }
+}
+
+/// Checks if raw storage keys under the key prefixes of a merkle sub-tree
+/// belong to the sub-tree, mirroring [`StoreType::sub_key`] without parsing
+/// the keys.
+#[derive(Debug)]
+struct RawStoreFilter {
+    store: StoreType,
+    /// The raw key prefix of the Ethereum bridge pool
+    bridge_pool_prefix: String,
+    /// The raw key prefixes of the sub-trees that are disjoint from the
+    /// account sub-tree
+    other_prefixes: Vec<String>,
+}
+
+impl RawStoreFilter {
+    fn new(store: StoreType) -> Self {
+        let other_prefixes = [
+            StoreType::Ibc,
+            StoreType::PoS,
+            StoreType::NoDiff,
+            StoreType::CommitData,
+        ]
+        .iter()
+        .flat_map(StoreType::key_prefixes)
+        .map(|prefix| prefix.to_string())
+        .collect();
+        Self {
+            store,
+            bridge_pool_prefix: Key::from(BRIDGE_POOL_ADDRESS.to_db_key())
+                .to_string(),
+            other_prefixes,
+        }
+    }
+
+    fn contains(&self, raw_key: &str) -> bool {
+        let (first, rest) = match raw_key.split_once(KEY_SEGMENT_SEPARATOR) {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (raw_key, None),
+        };
+        let is_pending_transfer = || {
+            first == self.bridge_pool_prefix
+                && rest.is_some_and(|seg| {
+                    !seg.contains(KEY_SEGMENT_SEPARATOR)
+                        && !seg.starts_with(RESERVED_ADDRESS_PREFIX)
+                        && !eth_bridge_pool::Segments::ALL.contains(&seg)
+                })
+        };
+        match self.store {
+            StoreType::Account => {
+                !self.other_prefixes.iter().any(|prefix| prefix == first)
+                    && !is_pending_transfer()
+            }
+            StoreType::BridgePool => is_pending_transfer(),
+            _ => true,
+        }
+    }
+}
+
 impl<'iter, D> Iterator for PrefixIter<'iter, D>
 where
     D: DB + DBIter<'iter>,
@@ -536,6 +769,8 @@ where
                         what = Next::ReturnStorage;
                     }
                     (Some((storage_key, _, _)), Some((wl_key, _))) => {
+                        let storage_key = canonical_key(storage_key);
+                        let wl_key = canonical_key(wl_key);
                         if wl_key <= storage_key {
                             what = Next::ReturnWl {
                                 advance_storage: wl_key == storage_key,
@@ -586,6 +821,19 @@ where
     }
 }
 
+/// Normalize a raw storage key with [`Key::canonicalize`], so that
+/// keys differing only by trailing separators are merged as one. Keys without
+/// a trailing separator are returned as is, without parsing.
+fn canonical_key(key: &str) -> Cow<'_, str> {
+    if !key.ends_with(KEY_SEGMENT_SEPARATOR) {
+        return Cow::Borrowed(key);
+    }
+    match Key::parse(key) {
+        Ok(parsed) => Cow::Owned(parsed.canonicalize().to_string()),
+        Err(_) => Cow::Borrowed(key),
+    }
+}
+
 /// Helpers for testing components that depend on storage
 #[cfg(any(test, feature = "testing"))]
 pub mod testing {
@@ -621,6 +869,46 @@ pub mod testing {
         true
     }
 
+    /// A snapshot of the committed storage and the write log of a
+    /// [`TestState`].
+    #[derive(Debug, Clone)]
+    pub struct TestStateSnapshot {
+        db: MockDB,
+        write_log: WriteLog,
+    }
+
+    impl TestState {
+        /// Capture the committed storage and the write log. The in-memory
+        /// state is not captured, but it's reloaded from the committed
+        /// storage on [`TestState::restore`].
+        pub fn snapshot(&self) -> TestStateSnapshot {
+            TestStateSnapshot {
+                db: self.0.db.clone(),
+                write_log: self.0.write_log.clone(),
+            }
+        }
+
+        /// Restore the committed storage and the write log from a snapshot
+        /// and reload the in-memory state (e.g. the merkle tree and the block
+        /// height) of the last block committed in the restored storage. As in
+        /// the ledger, the blocks must have been committed at non-zero
+        /// heights for their merkle tree to be reloaded.
+        pub fn restore(&mut self, snapshot: TestStateSnapshot) {
+            let TestStateSnapshot { db, write_log } = snapshot;
+            self.0.db = db;
+            self.0.write_log = write_log;
+            let in_mem = &self.0.in_mem;
+            self.0.in_mem = InMemoryState {
+                chain_id: in_mem.chain_id.clone(),
+                native_token: in_mem.native_token.clone(),
+                storage_read_past_height_limit: in_mem
+                    .storage_read_past_height_limit,
+                ..Default::default()
+            };
+            self.load_last_state();
+        }
+    }
+
     /// In memory State for testing.
     pub type InMemoryState = InMemory<Sha256Hasher>;
 
@@ -671,12 +959,14 @@ pub mod testing {
 )]
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, BTreeSet};
 
+    use assert_matches::assert_matches;
     use chrono::{TimeZone, Utc};
     use merkle_tree::NO_DIFF_KEY_PREFIX;
-    use namada_core::address::InternalAddress;
+    use namada_core::address::{self, InternalAddress};
     use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
+    use namada_core::collections::HashMap;
     use namada_core::keccak::KeccakHash;
     use namada_core::parameters::{EpochDuration, Parameters};
     use namada_core::storage::DbKeySeg;
@@ -1067,6 +1357,996 @@ mod tests {
         assert!(res2.is_none());
     }
 
+    #[test]
+    fn test_read_bytes_with_epoch() {
+        let mut state = TestState::default();
+        state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(0));
+
+        let key1 = test_key_1();
+        let val1 = 1u64;
+        let key2 = test_key_2();
+        let val2 = 2u64;
+        let other_key = Key::parse("other").unwrap();
+
+        // Write key1 in epoch 0 and commit it
+        state.write(&key1, val1).unwrap();
+        state.commit_block().unwrap();
+
+        // Move to a new epoch and commit a block that doesn't touch key1
+        state.in_mem_mut().block.height = BlockHeight(1);
+        state.in_mem_mut().block.epoch = Epoch(1);
+        state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(1));
+        state.write(&other_key, 0u64).unwrap();
+        state.commit_block().unwrap();
+        state.in_mem_mut().block.height = BlockHeight(2);
+
+        // Write key2 in the current tx
+        let _ = state
+            .write_log_mut()
+            .write(&key2, val2.serialize_to_vec())
+            .unwrap();
+
+        let (value, epoch) =
+            state.read_bytes_with_epoch(&key1).unwrap().unwrap();
+        assert_eq!(u64::try_from_slice(&value).unwrap(), val1);
+        assert_eq!(epoch, Epoch(0));
+
+        let (value, epoch) =
+            state.read_bytes_with_epoch(&key2).unwrap().unwrap();
+        assert_eq!(u64::try_from_slice(&value).unwrap(), val2);
+        assert_eq!(epoch, Epoch(1));
+
+        // Overwrite key1 in the current tx
+        let _ = state
+            .write_log_mut()
+            .write(&key1, 3u64.serialize_to_vec())
+            .unwrap();
+        let (value, epoch) =
+            state.read_bytes_with_epoch(&key1).unwrap().unwrap();
+        assert_eq!(u64::try_from_slice(&value).unwrap(), 3);
+        assert_eq!(epoch, Epoch(1));
+
+        let missing = Key::parse("missing").unwrap();
+        assert!(state.read_bytes_with_epoch(&missing).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut state = TestState::default();
+        let key1 = test_key_1();
+        let key2 = test_key_2();
+
+        state.in_mem_mut().block.height = BlockHeight(1);
+        state.write(&key1, 1u64).unwrap();
+        state.commit_block().unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&key2, 2u64.serialize_to_vec())
+            .unwrap();
+
+        let snapshot = state.snapshot();
+        let snapshot_height = state.in_mem().get_last_block_height();
+        let snapshot_root = state.in_mem().merkle_root();
+
+        // Mutate both the committed storage and the write log
+        state.in_mem_mut().block.height = BlockHeight(2);
+        state.write(&key1, 10u64).unwrap();
+        state.commit_block().unwrap();
+        let _ = state.write_log_mut().delete(&key2).unwrap();
+        state.write(&Key::parse("other").unwrap(), 3u64).unwrap();
+        assert_eq!(state.read::<u64>(&key1).unwrap(), Some(10));
+        assert_eq!(state.read::<u64>(&key2).unwrap(), None);
+
+        state.restore(snapshot);
+
+        // The in-memory state is that of the restored block
+        assert_eq!(state.in_mem().get_last_block_height(), snapshot_height);
+        assert_eq!(state.in_mem().merkle_root(), snapshot_root);
+        assert_eq!(state.read::<u64>(&key1).unwrap(), Some(1));
+        let (committed, _) = state.db_read(&key1).unwrap();
+        assert_eq!(committed, Some(1u64.serialize_to_vec()));
+        assert_eq!(state.read::<u64>(&key2).unwrap(), Some(2));
+        assert!(!state.has_key(&Key::parse("other").unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_increment_decrement() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+
+        // Increment from an absent value
+        assert_eq!(state.increment(&key, 5).unwrap(), 5);
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(5));
+
+        // Commit and continue from the value in storage
+        state.commit_block().unwrap();
+        assert_eq!(state.increment(&key, 2).unwrap(), 7);
+        assert_eq!(state.decrement(&key, 3).unwrap(), 4);
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(4));
+
+        // Overflow is rejected and the value is left unchanged
+        assert!(state.increment(&key, u64::MAX).is_err());
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(4));
+
+        // Underflow is rejected and the value is left unchanged
+        assert!(state.decrement(&key, 5).is_err());
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(4));
+
+        // Decrementing an absent value underflows
+        assert!(state.decrement(&test_key_2(), 1).is_err());
+        assert_eq!(state.read::<u64>(&test_key_2()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_move_value() {
+        let mut state = TestState::default();
+        let from = test_key_1();
+        let to = test_key_2();
+
+        // Move a present value
+        state.write(&from, 1_u64).unwrap();
+        state.write(&to, 2_u64).unwrap();
+        assert!(namada_storage::move_value(&mut state, &from, &to).unwrap());
+        assert!(!state.has_key(&from).unwrap());
+        assert_eq!(state.read::<u64>(&to).unwrap(), Some(1));
+
+        // An absent source leaves the target untouched
+        assert!(!namada_storage::move_value(&mut state, &from, &to).unwrap());
+        assert_eq!(state.read::<u64>(&to).unwrap(), Some(1));
+
+        // Moving a key onto itself keeps the value
+        assert!(!namada_storage::move_value(&mut state, &to, &to).unwrap());
+        assert_eq!(state.read::<u64>(&to).unwrap(), Some(1));
+    }
+
+    #[test]
+    fn test_copy_prefix() {
+        let mut state = TestState::default();
+        let from = Key::parse("from").unwrap();
+        let to = Key::parse("to").unwrap();
+        let tails = ["a", "b/c", "d"];
+        for (i, tail) in (0_u64..).zip(tails) {
+            let key = from.join(&Key::parse(tail).unwrap());
+            state.write(&key, i).unwrap();
+        }
+        state.commit_block().unwrap();
+        // A key outside of the prefix is not copied
+        state
+            .write(&Key::parse("fromage").unwrap(), 10_u64)
+            .unwrap();
+
+        assert_eq!(
+            namada_storage::copy_prefix(&mut state, &from, &to).unwrap(),
+            3
+        );
+        for (i, tail) in (0_u64..).zip(tails) {
+            let tail = Key::parse(tail).unwrap();
+            assert_eq!(state.read::<u64>(&to.join(&tail)).unwrap(), Some(i));
+            assert_eq!(state.read::<u64>(&from.join(&tail)).unwrap(), Some(i));
+        }
+
+        // Copying into a nested prefix of the source only copies the keys
+        // that existed before the copy
+        let nested = from.join(&Key::parse("nested").unwrap());
+        assert_eq!(
+            namada_storage::copy_prefix(&mut state, &from, &nested).unwrap(),
+            3
+        );
+        let nested_b_c = nested.join(&Key::parse("b/c").unwrap());
+        assert_eq!(state.read::<u64>(&nested_b_c).unwrap(), Some(1));
+    }
+
+    #[test]
+    fn test_delete_if_equals() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        state.write(&key, 1_u64).unwrap();
+        state.commit_block().unwrap();
+
+        // A mismatched value is kept
+        let other = 2_u64.serialize_to_vec();
+        assert!(
+            !namada_storage::delete_if_equals(&mut state, &key, &other)
+                .unwrap()
+        );
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+
+        // An uncommitted value is compared
+        state.write(&key, 2_u64).unwrap();
+        assert!(
+            namada_storage::delete_if_equals(&mut state, &key, &other).unwrap()
+        );
+        assert!(!state.has_key(&key).unwrap());
+
+        // An absent key is a no-op
+        assert!(
+            !namada_storage::delete_if_equals(&mut state, &key, &other)
+                .unwrap()
+        );
+        assert!(!state.has_key(&key).unwrap());
+    }
+
+    #[test]
+    fn test_init_once() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        assert!(namada_storage::init_once(&mut state, &key, &1_u64).unwrap());
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+
+        // A second call doesn't overwrite the uncommitted value
+        assert!(!namada_storage::init_once(&mut state, &key, &2_u64).unwrap());
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+
+        // Nor the committed one
+        state.commit_block().unwrap();
+        assert!(!namada_storage::init_once(&mut state, &key, &3_u64).unwrap());
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+    }
+
+    #[test]
+    fn test_commit_stats() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        let other_key = test_key_2();
+
+        state.write_bytes(&key, [0_u8; 10]).unwrap();
+        state.write_bytes(&other_key, [0_u8; 4]).unwrap();
+        let stats = state.commit_block_with_stats().unwrap();
+        assert_eq!(
+            stats,
+            CommitStats {
+                net_bytes_delta: 14,
+                read_stats: None,
+            }
+        );
+
+        // Overwrite with a smaller value and delete the other key
+        state.write_bytes(&key, [0_u8; 3]).unwrap();
+        state.delete(&other_key).unwrap();
+        let stats = state.commit_block_with_stats().unwrap();
+        assert_eq!(
+            stats,
+            CommitStats {
+                net_bytes_delta: -11,
+                read_stats: None,
+            }
+        );
+
+        // Rewriting the same value nets out
+        state.write_bytes(&key, [0_u8; 3]).unwrap();
+        let stats = state.commit_block_with_stats().unwrap();
+        assert_eq!(stats, CommitStats::default());
+    }
+
+    #[test]
+    fn test_read_only_storage() {
+        let mut state = TestState::default();
+        let prefix = Key::parse("prefix").unwrap();
+        let key = prefix.push(&0_u64).unwrap();
+        let other_key = prefix.push(&1_u64).unwrap();
+        state.write(&key, 1_u64).unwrap();
+        state.commit_block().unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&other_key, 2_u64.serialize_to_vec())
+            .unwrap();
+
+        let mut read_only = namada_storage::ReadOnlyStorage(&state);
+        assert_eq!(read_only.read::<u64>(&key).unwrap(), Some(1));
+        assert_eq!(read_only.read::<u64>(&other_key).unwrap(), Some(2));
+        assert!(read_only.has_key(&key).unwrap());
+        assert_eq!(
+            namada_storage::iter_prefix_keys(&read_only, &prefix)
+                .unwrap()
+                .count(),
+            2
+        );
+
+        assert_matches!(
+            read_only.write(&key, 2_u64),
+            Err(namada_storage::Error::ReadOnly { key: err_key })
+                if err_key == key
+        );
+        assert_matches!(
+            read_only.delete(&key),
+            Err(namada_storage::Error::ReadOnly { .. })
+        );
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+    }
+
+    #[test]
+    fn test_read_tagged() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        assert_eq!(
+            namada_storage::read_tagged::<_, u64>(&state, &key).unwrap(),
+            None
+        );
+
+        namada_storage::write_tagged(&mut state, &key, &1_u64).unwrap();
+        assert_eq!(
+            namada_storage::read_tagged::<_, u64>(&state, &key).unwrap(),
+            Some(1)
+        );
+
+        // Reading the value as another type is rejected
+        assert!(namada_storage::read_tagged::<_, bool>(&state, &key).is_err());
+        assert!(
+            namada_storage::read_tagged::<_, BlockHeight>(&state, &key)
+                .is_err()
+        );
+
+        // So is reading an untagged value
+        let untagged_key = test_key_2();
+        state.write_bytes(&untagged_key, []).unwrap();
+        assert!(
+            namada_storage::read_tagged::<_, u64>(&state, &untagged_key)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_block_account_writes_reset_on_commit() {
+        let mut state = TestState::default();
+        let owner = address::testing::established_address_1();
+        let key = Key::from(owner.to_db_key())
+            .push(&"key".to_string())
+            .unwrap();
+        let owners = BTreeSet::from([owner.clone()]);
+
+        let _ = state.write_log_mut().write(&key, vec![1]).unwrap();
+        state.write_log_mut().commit_batch_and_current_tx();
+        assert_eq!(
+            state.write_log().block_account_writes(&owners).unwrap()[&owner],
+            1
+        );
+
+        // The count starts over in a new block
+        state.commit_block().unwrap();
+        assert_eq!(
+            state.write_log().block_account_writes(&owners).unwrap()[&owner],
+            0
+        );
+    }
+
+    #[test]
+    fn test_read_last_modified_height() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        let other_key = test_key_2();
+
+        // Write the key at height 1 and commit it
+        state.in_mem_mut().block.height = BlockHeight(1);
+        state.write(&key, 1_u64).unwrap();
+        state.commit_block().unwrap();
+
+        // Commit a block that doesn't touch the key
+        state.in_mem_mut().block.height = BlockHeight(2);
+        state.write(&other_key, 2_u64).unwrap();
+        state.commit_block().unwrap();
+        state.in_mem_mut().block.height = BlockHeight(3);
+
+        assert_eq!(
+            state.read_last_modified_height(&key).unwrap(),
+            Some(BlockHeight(1))
+        );
+        assert_eq!(
+            state.read_last_modified_height(&other_key).unwrap(),
+            Some(BlockHeight(2))
+        );
+
+        // A key modified in the write log is reported at the current height
+        let _ = state.write_log_mut().write(&key, 3_u64.serialize_to_vec());
+        assert_eq!(
+            state.read_last_modified_height(&key).unwrap(),
+            Some(BlockHeight(3))
+        );
+
+        // A never written key has no height
+        let missing = Key::parse("missing").unwrap();
+        assert_eq!(state.read_last_modified_height(&missing).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_last_modified_height_untracked() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+
+        // Write the key at height 1, before the oldest stored epoch
+        state.in_mem_mut().block.height = BlockHeight(1);
+        state.write(&key, 1_u64).unwrap();
+        state.commit_block().unwrap();
+        state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(2));
+        state.in_mem_mut().block.height = BlockHeight(2);
+        state.commit_block().unwrap();
+        state.in_mem_mut().block.height = BlockHeight(3);
+
+        // The key is present, but no diff of its last write is found
+        assert!(state.has_key(&key).unwrap());
+        assert_eq!(state.read_last_modified_height(&key).unwrap(), None);
+        assert_eq!(state.read_bytes_with_epoch(&key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_bytes_committed() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        state.write(&key, 1_u64).unwrap();
+        state.commit_block().unwrap();
+
+        let _ = state.write_log_mut().write(&key, 2_u64.serialize_to_vec());
+        assert_eq!(
+            state.read_bytes(&key).unwrap(),
+            Some(2_u64.serialize_to_vec())
+        );
+        assert_eq!(
+            state.read_bytes_committed(&key).unwrap(),
+            Some(1_u64.serialize_to_vec())
+        );
+
+        // A key deleted in the write log is still committed
+        let _ = state.write_log_mut().delete(&key);
+        assert_eq!(state.read_bytes(&key).unwrap(), None);
+        assert_eq!(
+            state.read_bytes_committed(&key).unwrap(),
+            Some(1_u64.serialize_to_vec())
+        );
+    }
+
+    #[test]
+    fn test_write_monotonic_u64() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        namada_storage::write_monotonic_u64(&mut state, &key, 1).unwrap();
+        state.commit_block().unwrap();
+
+        // Increasing and equal values are accepted
+        namada_storage::write_monotonic_u64(&mut state, &key, 3).unwrap();
+        namada_storage::write_monotonic_u64(&mut state, &key, 3).unwrap();
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(3));
+
+        // Decreasing below the uncommitted value is rejected
+        assert!(
+            namada_storage::write_monotonic_u64(&mut state, &key, 2).is_err()
+        );
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_write_if_height_at_least() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        state.in_mem_mut().block.height = BlockHeight(10);
+
+        // Below the threshold
+        assert!(
+            !namada_storage::write_if_height_at_least(
+                &mut state,
+                &key,
+                1_u64,
+                BlockHeight(11)
+            )
+            .unwrap()
+        );
+        assert!(!state.has_key(&key).unwrap());
+
+        // At the threshold
+        assert!(
+            namada_storage::write_if_height_at_least(
+                &mut state,
+                &key,
+                2_u64,
+                BlockHeight(10)
+            )
+            .unwrap()
+        );
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(2));
+
+        // Above the threshold
+        assert!(
+            namada_storage::write_if_height_at_least(
+                &mut state,
+                &key,
+                3_u64,
+                BlockHeight(9)
+            )
+            .unwrap()
+        );
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_read_state() {
+        let mut state = TestState::default();
+        let empty = test_key_1();
+        let deleted = test_key_2();
+        let never_written = storage::Key::parse("never_written").unwrap();
+
+        let _ = state.write_log_mut().write(&empty, vec![]).unwrap();
+        state.write(&deleted, 1_u64).unwrap();
+        state.commit_block().unwrap();
+        let _ = state.write_log_mut().delete(&deleted).unwrap();
+
+        // An empty value in the write log is present
+        assert_eq!(
+            state.read_state(&empty).unwrap(),
+            namada_storage::KeyState::Present(vec![])
+        );
+        assert_eq!(
+            state.read_state(&never_written).unwrap(),
+            namada_storage::KeyState::Absent
+        );
+        assert_eq!(
+            state.read_state(&deleted).unwrap(),
+            namada_storage::KeyState::Absent
+        );
+    }
+
+    #[test]
+    fn test_read_required() {
+        let mut state = TestState::default();
+        let present = test_key_1();
+        let absent = test_key_2();
+        state.write(&present, 1_u64).unwrap();
+
+        assert_eq!(
+            namada_storage::read_required::<u64, _>(&state, &present).unwrap(),
+            1
+        );
+        let err = namada_storage::read_required::<u64, _>(&state, &absent)
+            .unwrap_err();
+        assert!(err.to_string().contains(&absent.to_string()));
+        assert_matches!(
+            err,
+            namada_storage::Error::KeyNotFound { key } if key == absent
+        );
+    }
+
+    #[test]
+    fn test_read_stats() {
+        let mut state = TestState::default();
+        let committed = test_key_1();
+        let pending = test_key_2();
+        state.write(&committed, 1_u64).unwrap();
+        state.commit_block().unwrap();
+
+        // Reads are not recorded until enabled
+        assert_eq!(state.read::<u64>(&committed).unwrap(), Some(1));
+        assert_eq!(state.write_log().read_stats(), None);
+
+        state.write_log_mut().enable_read_stats();
+        let _ = state
+            .write_log_mut()
+            .write(&pending, 2_u64.serialize_to_vec())
+            .unwrap();
+
+        // Two reads from the write log and one from storage
+        assert_eq!(state.read::<u64>(&pending).unwrap(), Some(2));
+        assert_eq!(state.read::<u64>(&pending).unwrap(), Some(2));
+        assert_eq!(state.read::<u64>(&committed).unwrap(), Some(1));
+        let expected = write_log::ReadStats {
+            log_hits: 2,
+            storage_hits: 1,
+        };
+        assert_eq!(state.write_log().read_stats(), Some(expected));
+
+        // The stats of the block are returned on commit and reset for the
+        // next block
+        state.commit_tx_batch();
+        let stats = state.commit_block_with_stats().unwrap();
+        assert_eq!(stats.read_stats, Some(expected));
+        assert_eq!(
+            state.write_log().read_stats(),
+            Some(write_log::ReadStats::default())
+        );
+    }
+
+    #[test]
+    fn test_iter_merges_canonical_keys() {
+        let mut state = TestState::default();
+        let prefix = Key::parse("a").unwrap();
+        let trailing = Key::parse("a/b/").unwrap();
+        let key = Key::parse("a/b").unwrap();
+        assert_eq!(trailing.canonicalize(), key);
+
+        state.db_write(&trailing, 1_u64.serialize_to_vec()).unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&key, 2_u64.serialize_to_vec())
+            .unwrap();
+
+        // The keys are treated as one, with the write log value taking
+        // precedence
+        let items: Vec<(String, Vec<u8>, _)> =
+            iter_prefix_post(state.write_log(), state.db(), &prefix)
+                .unwrap()
+                .0
+                .collect();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].0, key.to_string());
+        assert_eq!(items[0].1, 2_u64.serialize_to_vec());
+    }
+
+    #[test]
+    fn test_read_with_proof() {
+        let mut state = TestState::default();
+        let ibc_key = |seg: &str| {
+            Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+                .push(&seg.to_owned())
+                .unwrap()
+        };
+        let present = ibc_key("present");
+        let absent = ibc_key("absent");
+        state.write(&present, 1_u64).unwrap();
+        state.in_mem_mut().block.height = BlockHeight(1);
+        state.commit_block().unwrap();
+        let height = state.in_mem().get_last_block_height();
+
+        // A present key comes with a membership proof
+        let (value, proof) = state.read_with_proof(&present).unwrap();
+        let value = value.unwrap();
+        assert_eq!(value, 1_u64.serialize_to_vec());
+        assert_eq!(
+            proof,
+            state.get_existence_proof(&present, &value, height).unwrap()
+        );
+
+        // An absent key comes with a non-membership proof
+        let (value, proof) = state.read_with_proof(&absent).unwrap();
+        assert!(value.is_none());
+        assert_eq!(
+            proof,
+            state.get_non_existence_proof(&absent, height).unwrap()
+        );
+
+        // Uncommitted writes are not visible
+        let _ = state
+            .write_log_mut()
+            .write(&absent, 2_u64.serialize_to_vec())
+            .unwrap();
+        let (value, _proof) = state.read_with_proof(&absent).unwrap();
+        assert!(value.is_none());
+    }
+
+    #[test]
+    fn test_write_option() {
+        let mut state = TestState::default();
+        let key = test_key_1();
+        state.write_option(&key, Some(&1_u64)).unwrap();
+        state.commit_block().unwrap();
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(1));
+
+        // Clearing a previously present key makes it absent
+        state.write_option::<u64>(&key, None).unwrap();
+        assert_eq!(state.read::<u64>(&key).unwrap(), None);
+        assert!(!state.has_key(&key).unwrap());
+        state.commit_block().unwrap();
+        assert_eq!(state.read::<u64>(&key).unwrap(), None);
+
+        state.write_option(&key, Some(&2_u64)).unwrap();
+        assert_eq!(state.read::<u64>(&key).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_iter_prefix_in_store() {
+        use namada_core::eth_bridge_pool::{
+            BRIDGE_POOL_ADDRESS, Segments, get_key_from_hash,
+        };
+        use namada_core::keccak::KeccakHash;
+
+        let mut state = TestState::default();
+        let prefix = Key::from(BRIDGE_POOL_ADDRESS.to_db_key());
+        let signed_root_key = prefix
+            .push(&Segments::VALUES.signed_root.to_owned())
+            .unwrap();
+        let pending_key_1 = get_key_from_hash(&KeccakHash([1; 32]));
+        let pending_key_2 = get_key_from_hash(&KeccakHash([2; 32]));
+        state.write(&signed_root_key, 1_u64).unwrap();
+        state.write(&pending_key_1, 2_u64).unwrap();
+        let pos_key = Key::from(address::POS.to_db_key())
+            .push(&"key".to_owned())
+            .unwrap();
+        state.write(&pos_key, 4_u64).unwrap();
+        state.commit_block().unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&pending_key_2, 3_u64.serialize_to_vec());
+
+        let keys_in_store = |store, prefix: &Key| {
+            state
+                .iter_prefix_in_store(store, prefix)
+                .unwrap()
+                .map(|item| item.unwrap().0)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            keys_in_store(StoreType::BridgePool, &prefix),
+            vec![pending_key_1.to_string(), pending_key_2.to_string()]
+        );
+        assert_eq!(
+            keys_in_store(StoreType::Account, &prefix),
+            vec![signed_root_key.to_string()]
+        );
+        assert!(keys_in_store(StoreType::Ibc, &prefix).is_empty());
+
+        // A prefix that contains the sub-tree is narrowed down to the
+        // sub-tree's key prefixes
+        assert_eq!(
+            keys_in_store(StoreType::BridgePool, &Key::default()),
+            vec![pending_key_1.to_string(), pending_key_2.to_string()]
+        );
+        assert_eq!(
+            keys_in_store(StoreType::PoS, &Key::default()),
+            vec![pos_key.to_string()]
+        );
+    }
+
+    #[test]
+    fn test_iter_prefix_in_store_gas() {
+        use std::cell::RefCell;
+
+        use namada_core::eth_bridge_pool::{
+            BRIDGE_POOL_ADDRESS, Segments, get_key_from_hash,
+        };
+        use namada_gas::{TxGasMeter, VpGasMeter};
+
+        let mut state = TestState::default();
+        let prefix = Key::from(BRIDGE_POOL_ADDRESS.to_db_key());
+        let signed_root_key = prefix
+            .push(&Segments::VALUES.signed_root.to_owned())
+            .unwrap();
+        state.write(&signed_root_key, 1_u64).unwrap();
+        for i in 0..3 {
+            let pending_key = get_key_from_hash(&KeccakHash([i; 32]));
+            state.write(&pending_key, u64::from(i)).unwrap();
+        }
+        state.commit_block().unwrap();
+
+        let tx_gas_meter = TxGasMeter::new(u64::MAX, 1);
+
+        // Iterating the account keys under the bridge pool prefix costs the
+        // same as iterating all the keys, even though only one is returned
+        let store_gas_meter =
+            RefCell::new(VpGasMeter::new_from_tx_meter(&tx_gas_meter));
+        let store_state = VpHostEnvState {
+            write_log: state.write_log(),
+            db: state.db(),
+            in_mem: state.in_mem(),
+            gas_meter: &store_gas_meter,
+        };
+        let keys: Vec<String> = store_state
+            .iter_prefix_in_store(StoreType::Account, &prefix)
+            .unwrap()
+            .map(|item| item.unwrap().0)
+            .collect();
+        assert_eq!(keys, vec![signed_root_key.to_string()]);
+
+        let all_gas_meter =
+            RefCell::new(VpGasMeter::new_from_tx_meter(&tx_gas_meter));
+        let all_state = VpHostEnvState {
+            write_log: state.write_log(),
+            db: state.db(),
+            in_mem: state.in_mem(),
+            gas_meter: &all_gas_meter,
+        };
+        let mut iter = all_state.iter_prefix(&prefix).unwrap();
+        let mut all_keys = 0;
+        while all_state.iter_next(&mut iter).unwrap().is_some() {
+            all_keys += 1;
+        }
+        assert_eq!(all_keys, 4);
+
+        let store_gas = store_gas_meter.borrow().get_vp_consumed_gas();
+        assert!(store_gas > Gas::default());
+        assert_eq!(store_gas, all_gas_meter.borrow().get_vp_consumed_gas());
+    }
+
+    #[test]
+    fn test_iter_prefix_keys() {
+        let mut state = TestState::default();
+        let prefix = Key::parse("a").unwrap();
+        let keys: Vec<Key> =
+            (0_u64..4).map(|i| prefix.push(&i).unwrap()).collect();
+        for key in &keys[..3] {
+            state.write(key, 0_u64).unwrap();
+        }
+        state.commit_block().unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&keys[3], 0_u64.serialize_to_vec())
+            .unwrap();
+        // Delete a committed key in the write log
+        let _ = state.write_log_mut().delete(&keys[1]).unwrap();
+
+        let iter_keys: Vec<Key> =
+            namada_storage::iter_prefix_keys(&state, &prefix)
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+        let item_keys: Vec<Key> =
+            namada_storage::iter_prefix::<u64>(&state, &prefix)
+                .unwrap()
+                .map(|item| item.map(|(key, _val)| key))
+                .collect::<Result<_>>()
+                .unwrap();
+        assert_eq!(iter_keys, item_keys);
+        assert_eq!(
+            iter_keys,
+            vec![keys[0].clone(), keys[2].clone(), keys[3].clone()]
+        );
+    }
+
+    #[test]
+    fn test_read_map() {
+        let mut state = TestState::default();
+        let prefix = Key::parse("balances").unwrap();
+        let keys: Vec<Key> = ["alice", "bob", "carol"]
+            .iter()
+            .map(|owner| prefix.push(&owner.to_string()).unwrap())
+            .collect();
+        for (i, key) in keys.iter().enumerate() {
+            state.write(key, i as u64).unwrap();
+        }
+        state.commit_block().unwrap();
+
+        let map: HashMap<String, u64> = state.read_map(&prefix).unwrap();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("alice"), Some(&0));
+        assert_eq!(map.get("bob"), Some(&1));
+        assert_eq!(map.get("carol"), Some(&2));
+
+        // Override and delete committed values in the write log
+        let _ = state
+            .write_log_mut()
+            .write(&keys[0], 10_u64.serialize_to_vec())
+            .unwrap();
+        let _ = state.write_log_mut().delete(&keys[1]).unwrap();
+
+        let map: HashMap<String, u64> = state.read_map(&prefix).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("alice"), Some(&10));
+        assert_eq!(map.get("bob"), None);
+        assert_eq!(map.get("carol"), Some(&2));
+    }
+
+    #[test]
+    fn test_read_checked() {
+        let mut state = TestState::default();
+        let key = Key::parse("code").unwrap();
+        let code = b"wasm bytecode".to_vec();
+        let _ = state.write_log_mut().write(&key, code.clone()).unwrap();
+
+        let hash = Hash::sha256(&code);
+        assert_eq!(state.read_checked(&key, &hash).unwrap(), code);
+
+        let other_hash = Hash::sha256(b"other bytecode");
+        assert!(state.read_checked(&key, &other_hash).is_err());
+
+        let missing = Key::parse("missing").unwrap();
+        assert_matches!(
+            state.read_checked(&missing, &hash),
+            Err(namada_storage::Error::KeyNotFound { key }) if key == missing
+        );
+    }
+
+    #[test]
+    fn test_merged_prefix_iter() {
+        let mut state = TestState::default();
+        let prefix = Key::parse("a").unwrap();
+        let keys: Vec<Key> =
+            (0_u64..4).map(|i| prefix.push(&i).unwrap()).collect();
+        for (i, key) in keys.iter().enumerate().take(2) {
+            state.write(key, i as u64).unwrap();
+        }
+        state.commit_block().unwrap();
+        for (i, key) in keys.iter().enumerate().skip(2) {
+            let _ = state
+                .write_log_mut()
+                .write(key, (i as u64).serialize_to_vec())
+                .unwrap();
+        }
+        let other = Key::parse("b").unwrap();
+        state.write(&other, 0_u64).unwrap();
+
+        // Items from both the storage and the write log are collected
+        let items: Vec<(Key, Vec<u8>)> =
+            namada_storage::MergedPrefixIter::new(&state, &prefix)
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+        let expected: Vec<(Key, Vec<u8>)> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.clone(), (i as u64).serialize_to_vec()))
+            .collect();
+        assert_eq!(items, expected);
+
+        // Items can be filtered with the standard combinators
+        let filtered: Vec<Key> =
+            namada_storage::MergedPrefixIter::new(&state, &prefix)
+                .unwrap()
+                .map(|item| item.unwrap())
+                .filter(|(_key, val)| {
+                    u64::try_from_slice(val).unwrap() % 2 == 1
+                })
+                .map(|(key, _val)| key)
+                .collect();
+        assert_eq!(filtered, vec![keys[1].clone(), keys[3].clone()]);
+    }
+
+    #[test]
+    fn test_iter_prefix_user() {
+        let mut state = TestState::default();
+        let pos_key =
+            Key::from(Address::Internal(InternalAddress::PoS).to_db_key())
+                .push(&"params".to_owned())
+                .unwrap();
+        let user_key =
+            Key::from(address::testing::established_address_1().to_db_key())
+                .push(&"data".to_owned())
+                .unwrap();
+        state.write(&pos_key, 1_u64).unwrap();
+        state.write(&user_key, 2_u64).unwrap();
+        state.commit_block().unwrap();
+
+        let items: Vec<(Key, u64)> =
+            namada_storage::iter_prefix_user(&state, &Key::default())
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+        assert_eq!(items, vec![(user_key, 2)]);
+    }
+
+    #[test]
+    fn test_pending_changes() {
+        use write_log::ChangeKind;
+
+        let mut state = TestState::default();
+        let no_op_write = test_key_1();
+        let update = test_key_2();
+        let delete = Key::parse("testing3").unwrap();
+        let insert = Key::parse("testing4").unwrap();
+        let no_op_delete = Key::parse("testing5").unwrap();
+
+        state.write(&no_op_write, 1_u64).unwrap();
+        state.write(&update, 2_u64).unwrap();
+        state.write(&delete, 3_u64).unwrap();
+        state.commit_block().unwrap();
+        assert!(state.pending_changes().unwrap().is_empty());
+
+        let write_log = state.write_log_mut();
+        let _ = write_log
+            .write(&no_op_write, 1_u64.serialize_to_vec())
+            .unwrap();
+        let _ = write_log.write(&update, 20_u64.serialize_to_vec()).unwrap();
+        let _ = write_log.delete(&delete).unwrap();
+        let _ = write_log.write(&insert, 4_u64.serialize_to_vec()).unwrap();
+        let _ = write_log.delete(&no_op_delete).unwrap();
+
+        assert_eq!(
+            state.pending_changes().unwrap(),
+            vec![
+                (no_op_write, ChangeKind::NoOp),
+                (update, ChangeKind::Update),
+                (delete, ChangeKind::Delete),
+                (insert, ChangeKind::Insert),
+                (no_op_delete, ChangeKind::NoOp),
+            ]
+        );
+    }
+
     proptest! {
         // Generate arb valid input for `test_prefix_iters_aux`
         #![proptest_config(Config {
@@ -22,7 +22,7 @@ use namada_storage::{
 };
 
 use crate::in_memory::InMemory;
-use crate::write_log::{StorageModification, WriteLog};
+use crate::write_log::{ReadStats, StorageModification, WriteLog};
 use crate::{
     DB, DBIter, EPOCH_SWITCH_BLOCKS_DELAY, Epoch, Error, Hash, Key, KeySeg,
     LastBlock, MembershipProof, MerkleTree, MerkleTreeError, ProofOps, Result,
@@ -66,6 +66,18 @@ where
     }
 }
 
+/// Statistics of the changes committed to the storage by a block
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommitStats {
+    /// The net number of bytes added to (if positive) or removed from (if
+    /// negative) the subspace storage by the block's write log. Overwrites
+    /// only count the difference from the previous value's size.
+    pub net_bytes_delta: i64,
+    /// The statistics of the reads of storage values in the block, if
+    /// enabled with [`WriteLog::enable_read_stats`]
+    pub read_stats: Option<ReadStats>,
+}
+
 /// State with a temporary write log. This is used for dry-running txs and ABCI
 /// prepare and processs proposal, which must not modify the actual state.
 #[derive(Debug)]
@@ -282,6 +294,12 @@ where
     /// Commit the current block's write log to the storage and commit the block
     /// to DB. Starts a new block write log.
     pub fn commit_block(&mut self) -> Result<()> {
+        self.commit_block_with_stats().map(|_stats| ())
+    }
+
+    /// Like [`FullAccessState::commit_block`], but also returns the
+    /// statistics of the changes committed by the block.
+    pub fn commit_block_with_stats(&mut self) -> Result<CommitStats> {
         if self.in_mem.last_epoch != self.in_mem.block.epoch {
             self.in_mem_mut()
                 .update_epoch_in_merkle_tree()
@@ -289,33 +307,49 @@ where
         }
 
         let mut batch = D::batch();
-        self.commit_write_log_block(&mut batch)
+        let stats = self
+            .commit_write_log_block(&mut batch)
             .into_storage_result()?;
-        self.commit_block_from_batch(batch).into_storage_result()
+        self.commit_block_from_batch(batch).into_storage_result()?;
+        Ok(stats)
     }
 
-    /// Commit the current block's write log to the storage. Starts a new block
-    /// write log.
+    /// Commit the current block's write log to the storage, returning the
+    /// statistics of the committed changes. Starts a new block write log.
     pub fn commit_write_log_block(
         &mut self,
         batch: &mut D::WriteBatch,
-    ) -> Result<()> {
+    ) -> Result<CommitStats> {
+        let read_stats = self.0.write_log.take_read_stats();
+        let mut net_bytes_delta: i64 = 0;
         for (key, entry) in
             std::mem::take(&mut self.0.write_log.block_write_log).into_iter()
         {
             match entry {
                 StorageModification::Write { value } => {
-                    self.batch_write_subspace_val(batch, &key, value)?;
+                    let size_diff =
+                        self.batch_write_subspace_val(batch, &key, value)?;
+                    net_bytes_delta = checked!(net_bytes_delta + size_diff)?;
                 }
                 StorageModification::Delete => {
-                    self.batch_delete_subspace_val(batch, &key)?;
+                    let removed_size =
+                        self.batch_delete_subspace_val(batch, &key)?;
+                    net_bytes_delta = checked!(net_bytes_delta - removed_size)?;
                 }
                 StorageModification::InitAccount { vp_code_hash } => {
-                    self.batch_write_subspace_val(batch, &key, vp_code_hash)?;
+                    let size_diff = self.batch_write_subspace_val(
+                        batch,
+                        &key,
+                        vp_code_hash,
+                    )?;
+                    net_bytes_delta = checked!(net_bytes_delta + size_diff)?;
                 }
             }
         }
         debug_assert!(self.0.write_log.block_write_log.is_empty());
+        self.0.write_log.block_account_writes.clear();
+        #[cfg(any(test, feature = "testing"))]
+        self.0.write_log.clear_mod_tracking();
 
         // Replay protections specifically. Starts with moving the current
         // hashes from the previous block to the general bucket
@@ -344,7 +378,12 @@ where
         if let Some(address_gen) = self.0.write_log.block_address_gen.take() {
             self.0.in_mem.address_gen = address_gen
         }
-        Ok(())
+        // The reads of the commit itself are not counted in the next block
+        let _ = self.0.write_log.take_read_stats();
+        Ok(CommitStats {
+            net_bytes_delta,
+            read_stats,
+        })
     }
 
     /// Start write batch.
@@ -1034,6 +1073,24 @@ where
         }
     }
 
+    /// Read the committed value at the given key together with its proof, both
+    /// at the last committed height, so that the proof always corresponds to
+    /// the returned value. The proof is an existence proof for a present key
+    /// and a non-existence proof otherwise. Note that uncommitted changes in
+    /// the write log are not taken into account.
+    pub fn read_with_proof(
+        &self,
+        key: &Key,
+    ) -> Result<(Option<Vec<u8>>, ProofOps)> {
+        let height = self.in_mem.get_last_block_height();
+        let (value, _gas) = self.db_read_with_height(key, height)?;
+        let proof = match &value {
+            Some(value) => self.get_existence_proof(key, value, height)?,
+            None => self.get_non_existence_proof(key, height)?,
+        };
+        Ok((value, proof))
+    }
+
     /// Rebuild Merkle tree with diffs in the DB.
     /// Base tree and the specified `store_type` subtree is rebuilt.
     /// If `store_type` isn't given, full Merkle tree is restored.
@@ -2,12 +2,15 @@
 //! before they are committed to the ledger's storage.
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use itertools::Itertools;
-use namada_core::address::{Address, EstablishedAddressGen};
+use namada_core::address::{Address, EstablishedAddressGen, InternalAddress};
 use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::hash::Hash;
+use namada_core::keccak::{KeccakHash, keccak_hash};
 use namada_core::{arith, storage};
 use namada_events::extend::{InnerTxHash, TxHash};
 use namada_events::{Event, EventToEmit, EventType};
@@ -43,6 +46,33 @@ pub enum Error {
     SizeDiffOverflow,
     #[error("Value length overflowed")]
     ValueLenOverflow,
+    #[error("Failed to decode the write log: {0}")]
+    Decode(std::io::Error),
+    #[error("Trying to merge a write log with temporary values")]
+    MergeTemporaryValue,
+    #[error(
+        "Trying to merge a write log that generated addresses into a write \
+         log that also generated addresses"
+    )]
+    MergeAddressGen,
+    #[error(
+        "Trying to merge a write log with writes to accounts' subspaces \
+         committed to its block write log"
+    )]
+    MergeAccountWrites,
+}
+
+/// An anomaly in the encoding of a storage key in the write log
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum KeyError {
+    #[error(
+        "Storage key {0} is parsed back as a different key, e.g. because one \
+         of its segments contains the segment separator"
+    )]
+    NoRoundTrip(storage::Key),
+    #[error("Storage key {key} contains an illegal character {illegal:?}")]
+    IllegalChar { key: storage::Key, illegal: char },
 }
 
 impl From<Error> for crate::Error {
@@ -55,7 +85,7 @@ impl From<Error> for crate::Error {
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// A storage modification
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub enum StorageModification {
     /// Write a new value
     Write {
@@ -73,9 +103,52 @@ pub enum StorageModification {
     },
 }
 
+/// The kind of change that a storage modification in the write log applies to
+/// the committed storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// A value is written to a key that isn't in storage
+    Insert,
+    /// A value different from the one in storage is written
+    Update,
+    /// A key present in storage is deleted
+    Delete,
+    /// The modification doesn't change the storage: either the same value as
+    /// in storage is written or a key that isn't in storage is deleted
+    NoOp,
+}
+
+impl ChangeKind {
+    /// Classify a storage modification against the committed value of its
+    /// key, if any.
+    pub fn classify(
+        modification: &StorageModification,
+        committed: Option<&[u8]>,
+    ) -> Self {
+        let written = match modification {
+            StorageModification::Write { value } => value.as_slice(),
+            StorageModification::InitAccount { vp_code_hash } => {
+                vp_code_hash.as_ref()
+            }
+            StorageModification::Delete => {
+                return if committed.is_some() {
+                    Self::Delete
+                } else {
+                    Self::NoOp
+                };
+            }
+        };
+        match committed {
+            None => Self::Insert,
+            Some(committed) if committed == written => Self::NoOp,
+            Some(_) => Self::Update,
+        }
+    }
+}
+
 /// The write log for a transaction. This allows managing the result of a single
 /// inner transaction inside a batch
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub(crate) struct TxWriteLog {
     // The generator of established addresses
     address_gen: Option<EstablishedAddressGen>,
@@ -103,7 +176,7 @@ impl Default for TxWriteLog {
 
 /// The write log for an already evaluated transaction of a batch. This allows
 /// managing the result of a single inner transaction inside a batch
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub(crate) struct BatchedTxWriteLog {
     // The generator of established addresses
     address_gen: Option<EstablishedAddressGen>,
@@ -135,8 +208,121 @@ impl std::cmp::PartialEq for WriteLogEvents {
 
 impl std::cmp::Eq for WriteLogEvents {}
 
+impl BorshSerialize for WriteLogEvents {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        let events: Vec<(String, &HashSet<Event>)> = self.tree.iter().collect();
+        BorshSerialize::serialize(&events, writer)
+    }
+}
+
+impl BorshDeserialize for WriteLogEvents {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        let events: Vec<(String, HashSet<Event>)> =
+            BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self {
+            tree: events.into_iter().collect(),
+        })
+    }
+}
+
+/// The number of storage reads satisfied by the write log and of those that
+/// fell through to the storage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReadStats {
+    /// Reads satisfied by the write log
+    pub log_hits: u64,
+    /// Reads that fell through to the storage
+    pub storage_hits: u64,
+}
+
+/// Counters of [`ReadStats`]. These are atomic, as the write log is shared
+/// between VPs running in parallel.
+#[derive(Debug, Default)]
+pub(crate) struct ReadStatsCounter {
+    log_hits: AtomicU64,
+    storage_hits: AtomicU64,
+}
+
+impl Clone for ReadStatsCounter {
+    fn clone(&self) -> Self {
+        Self {
+            log_hits: AtomicU64::new(self.log_hits.load(Ordering::Relaxed)),
+            storage_hits: AtomicU64::new(
+                self.storage_hits.load(Ordering::Relaxed),
+            ),
+        }
+    }
+}
+
+// The statistics don't take part in the comparison of write logs
+impl PartialEq for ReadStatsCounter {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ReadStatsCounter {}
+
+/// The originator of a storage modification in the write log
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ModSource {
+    /// A transaction
+    #[default]
+    Tx,
+    /// The native VP of the given internal address
+    NativeVp(InternalAddress),
+    /// The protocol
+    Protocol,
+}
+
+/// The sources of the latest storage modifications of the keys in the write
+/// log. These are only tracked for debugging, so that they cannot affect
+/// consensus.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModSources {
+    /// The source of the modifications of non-protocol writes
+    current: ModSource,
+    sources: HashMap<storage::Key, ModSource>,
+}
+
+// The sources don't take part in the comparison of write logs
+#[cfg(any(test, feature = "testing"))]
+impl PartialEq for ModSources {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Eq for ModSources {}
+
+/// All the storage modifications of the keys in the write log, in the order
+/// in which they were applied. These are only tracked for debugging, so that
+/// they cannot affect consensus.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModHistory(HashMap<storage::Key, Vec<StorageModification>>);
+
+// The history doesn't take part in the comparison of write logs
+#[cfg(any(test, feature = "testing"))]
+impl PartialEq for ModHistory {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Eq for ModHistory {}
+
 /// The write log storage
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct WriteLog {
     /// The generator of established addresses
     pub(crate) block_address_gen: Option<EstablishedAddressGen>,
@@ -153,6 +339,21 @@ pub struct WriteLog {
     /// managed in the normal write log because we need to commit them
     /// sometimes even on batch failure
     pub(crate) replay_protection: HashSet<Hash>,
+    /// The running count of the storage modifications of the subspaces of
+    /// non-internal accounts committed to the block write log
+    pub(crate) block_account_writes: HashMap<Address, u64>,
+    /// Statistics of the reads of storage values in the current block, only
+    /// recorded once enabled with [`WriteLog::enable_read_stats`]
+    #[borsh(skip)]
+    pub(crate) read_stats: Option<ReadStatsCounter>,
+    /// The sources of the storage modifications
+    #[cfg(any(test, feature = "testing"))]
+    #[borsh(skip)]
+    pub(crate) mod_sources: ModSources,
+    /// The history of the storage modifications
+    #[cfg(any(test, feature = "testing"))]
+    #[borsh(skip)]
+    pub(crate) mod_history: ModHistory,
 }
 
 /// Write log prefix iterator
@@ -179,11 +380,67 @@ impl Default for WriteLog {
             batch_write_log: Vec::with_capacity(5),
             tx_write_log: Default::default(),
             replay_protection: HashSet::with_capacity(1_000),
+            block_account_writes: HashMap::default(),
+            read_stats: None,
+            #[cfg(any(test, feature = "testing"))]
+            mod_sources: ModSources::default(),
+            #[cfg(any(test, feature = "testing"))]
+            mod_history: ModHistory::default(),
         }
     }
 }
 
 impl WriteLog {
+    /// Serialize all the uncommitted modifications of the write log, e.g. to
+    /// persist them for recovery after a crash
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.serialize_to_vec()
+    }
+
+    /// Start recording the statistics of the reads of storage values
+    pub fn enable_read_stats(&mut self) {
+        self.read_stats
+            .get_or_insert_with(ReadStatsCounter::default);
+    }
+
+    /// Record a read of a storage value, which was either satisfied by the
+    /// write log or fell through to the storage. This is a no-op unless the
+    /// statistics are enabled.
+    pub fn record_read(&self, from_log: bool) {
+        let Some(read_stats) = &self.read_stats else {
+            return;
+        };
+        let counter = if from_log {
+            &read_stats.log_hits
+        } else {
+            &read_stats.storage_hits
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Get the statistics of the reads recorded in the current block, if
+    /// enabled
+    pub fn read_stats(&self) -> Option<ReadStats> {
+        self.read_stats.as_ref().map(|read_stats| ReadStats {
+            log_hits: read_stats.log_hits.load(Ordering::Relaxed),
+            storage_hits: read_stats.storage_hits.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Get the statistics of the reads recorded in the current block, if
+    /// enabled, and reset them for the next block
+    pub(crate) fn take_read_stats(&self) -> Option<ReadStats> {
+        self.read_stats.as_ref().map(|read_stats| ReadStats {
+            log_hits: read_stats.log_hits.swap(0, Ordering::Relaxed),
+            storage_hits: read_stats.storage_hits.swap(0, Ordering::Relaxed),
+        })
+    }
+
+    /// Restore a write log serialized with [`WriteLog::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        BorshDeserialize::try_from_slice(bytes).map_err(Error::Decode)
+    }
+
     /// Read a non-temp value at the given key and return the value and the gas
     /// cost, returns [`None`] if the key is not present in the write log
     pub fn read(
@@ -328,6 +585,16 @@ impl WriteLog {
             None => len_signed,
         };
 
+        #[cfg(any(test, feature = "testing"))]
+        {
+            self.record_current_mod_source(key);
+            self.record_modification(
+                key,
+                StorageModification::Write {
+                    value: value.clone(),
+                },
+            );
+        }
         self.tx_write_log
             .write_log
             .insert(key.clone(), StorageModification::Write { value });
@@ -339,6 +606,48 @@ impl WriteLog {
         ))
     }
 
+    /// Write all the given keys and values at the tx level, or none of them,
+    /// and return the summed gas cost and the size difference of the writes.
+    /// If any of the writes fails, the writes of the batch that were already
+    /// applied are rolled back and the error is returned. See
+    /// [`WriteLog::write`] for the possible errors.
+    pub fn write_batch(
+        &mut self,
+        entries: &[(storage::Key, Vec<u8>)],
+    ) -> Result<(Gas, i64)> {
+        let mut applied = Vec::with_capacity(entries.len());
+        let written = entries.iter().try_fold(
+            (0_u64, 0_i64),
+            |(total_gas, total_size_diff), (key, value)| {
+                let prev = self.tx_write_log.write_log.get(key).cloned();
+                let (gas, size_diff) = self.write(key, value.clone())?;
+                applied.push((key.clone(), prev));
+                Ok::<_, Error>((
+                    checked!(total_gas + u64::from(gas))?,
+                    checked!(total_size_diff + size_diff)?,
+                ))
+            },
+        );
+        match written {
+            Ok((gas, size_diff)) => Ok((gas.into(), size_diff)),
+            Err(err) => {
+                // Restore the previous modifications in reverse order, so that
+                // a key written more than once recovers its original state
+                for (key, prev) in applied.into_iter().rev() {
+                    match prev {
+                        Some(prev) => {
+                            self.tx_write_log.write_log.insert(key, prev);
+                        }
+                        None => {
+                            self.tx_write_log.write_log.swap_remove(&key);
+                        }
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+
     /// Write a key and a value.
     /// Fails with [`Error::UpdateVpOfNewAccount`] when attempting to update a
     /// validity predicate of a new account that's not yet committed to storage.
@@ -352,6 +661,16 @@ impl WriteLog {
         if self.tx_write_log.tx_temp_log.contains_key(key) {
             return Err(Error::UpdateTemporaryValue);
         }
+        #[cfg(any(test, feature = "testing"))]
+        {
+            self.record_mod_source(key, ModSource::Protocol);
+            self.record_modification(
+                key,
+                StorageModification::Write {
+                    value: value.clone(),
+                },
+            );
+        }
         if let Some(prev) = self
             .block_write_log
             .insert(key.clone(), StorageModification::Write { value })
@@ -443,6 +762,11 @@ impl WriteLog {
         self.tx_write_log
             .write_log
             .insert(key.clone(), StorageModification::Delete);
+        #[cfg(any(test, feature = "testing"))]
+        {
+            self.record_current_mod_source(key);
+            self.record_modification(key, StorageModification::Delete);
+        }
         let gas = checked!(key.len() + size_diff)? as u64;
         let size_diff = i64::try_from(size_diff)
             .ok()
@@ -461,6 +785,11 @@ impl WriteLog {
         if key.is_validity_predicate().is_some() {
             return Err(Error::DeleteVp);
         }
+        #[cfg(any(test, feature = "testing"))]
+        {
+            self.record_mod_source(key, ModSource::Protocol);
+            self.record_modification(key, StorageModification::Delete);
+        }
         if let Some(prev) = self
             .block_write_log
             .insert(key.clone(), StorageModification::Delete)
@@ -476,6 +805,66 @@ impl WriteLog {
         Ok(())
     }
 
+    /// Set the source of the subsequent non-protocol storage modifications.
+    /// Protocol writes and deletes are always tagged [`ModSource::Protocol`].
+    #[cfg(any(test, feature = "testing"))]
+    pub fn set_mod_source(&mut self, source: ModSource) {
+        self.mod_sources.current = source;
+    }
+
+    /// Get the source of the latest storage modification of the given key
+    /// in the current block, if any. The source is kept even if the
+    /// modification is later dropped.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn mod_source(&self, key: &storage::Key) -> Option<&ModSource> {
+        self.mod_sources.sources.get(key)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    fn record_current_mod_source(&mut self, key: &storage::Key) {
+        let source = self.mod_sources.current.clone();
+        self.record_mod_source(key, source);
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    fn record_mod_source(&mut self, key: &storage::Key, source: ModSource) {
+        self.mod_sources.sources.insert(key.clone(), source);
+    }
+
+    /// Get the `n`-th storage modification applied to the given key in the
+    /// current block, counting from zero, if any. All the modifications are
+    /// kept until the block is committed, even if they are overwritten or
+    /// dropped.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn modification_at(
+        &self,
+        key: &storage::Key,
+        n: usize,
+    ) -> Option<&StorageModification> {
+        self.mod_history.0.get(key)?.get(n)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    fn record_modification(
+        &mut self,
+        key: &storage::Key,
+        modification: StorageModification,
+    ) {
+        self.mod_history
+            .0
+            .entry(key.clone())
+            .or_default()
+            .push(modification);
+    }
+
+    /// Clear the sources and the history of the storage modifications, once
+    /// the block is committed.
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn clear_mod_tracking(&mut self) {
+        self.mod_sources.sources.clear();
+        self.mod_history.0.clear();
+    }
+
     /// Initialize a new account and return the gas cost.
     pub fn init_account(
         &mut self,
@@ -497,6 +886,11 @@ impl WriteLog {
             .expect("Cannot overflow")) as u64)
             .checked_mul(STORAGE_WRITE_GAS_PER_BYTE)
             .expect("Canno overflow");
+        #[cfg(any(test, feature = "testing"))]
+        self.record_modification(
+            &key,
+            StorageModification::InitAccount { vp_code_hash },
+        );
         self.tx_write_log
             .write_log
             .insert(key, StorageModification::InitAccount { vp_code_hash });
@@ -553,6 +947,70 @@ impl WriteLog {
             .collect()
     }
 
+    /// Iterate the distinct non-temporary storage keys written or deleted in
+    /// the current transaction, in no particular order. An account initialized
+    /// in the transaction is only included with its validity predicate key.
+    pub fn iter_changed_keys(&self) -> impl Iterator<Item = &storage::Key> {
+        self.tx_write_log.write_log.keys()
+    }
+
+    /// Check that the keys of all the storage modifications in the write log
+    /// are parsed back into the same keys and don't contain any control
+    /// characters. Returns all the offending keys, sorted by the key.
+    pub fn validate_keys(&self) -> std::result::Result<(), Vec<KeyError>> {
+        let keys: BTreeSet<&storage::Key> = self
+            .block_write_log
+            .keys()
+            .chain(
+                self.batch_write_log
+                    .iter()
+                    .flat_map(|batch| batch.write_log.keys()),
+            )
+            .chain(self.tx_write_log.write_log.keys())
+            .collect();
+        let errors: Vec<KeyError> = keys
+            .into_iter()
+            .filter_map(|key| {
+                let raw = key.to_string();
+                if let Some(illegal) = raw.chars().find(|c| c.is_control()) {
+                    return Some(KeyError::IllegalChar {
+                        key: key.clone(),
+                        illegal,
+                    });
+                }
+                match storage::Key::parse(&raw) {
+                    Ok(parsed) if &parsed == key => None,
+                    _ => Some(KeyError::NoRoundTrip(key.clone())),
+                }
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Get the storage keys changed in the current transaction grouped by the
+    /// internal address that owns them, i.e. the internal address in the
+    /// first segment of a key. Keys that are not owned by an internal address
+    /// (e.g. keys of user accounts) are grouped under `None`. The keys of each
+    /// group are sorted.
+    pub fn changed_keys_by_subsystem(
+        &self,
+    ) -> HashMap<Option<InternalAddress>, Vec<storage::Key>> {
+        let mut grouped: HashMap<Option<InternalAddress>, Vec<storage::Key>> =
+            HashMap::new();
+        for key in self.get_keys() {
+            let subsystem = match key.fst_address() {
+                Some(Address::Internal(internal)) => Some(internal.clone()),
+                _ => None,
+            };
+            grouped.entry(subsystem).or_default().push(key);
+        }
+        grouped
+    }
+
     /// Get the storage keys changed in the current transaction (left) and
     /// the addresses of accounts initialized in the current transaction
     /// (right). The first vector excludes keys of validity predicates of
@@ -576,6 +1034,159 @@ impl WriteLog {
             })
     }
 
+    /// Count the non-temporary storage modifications of the subspaces of the
+    /// given `owners` (i.e. of the keys whose first address segment is the
+    /// owner) in the current block, including the current batch and
+    /// transaction. The modifications of the subspaces of internal addresses
+    /// are not counted.
+    pub fn block_account_writes(
+        &self,
+        owners: &BTreeSet<Address>,
+    ) -> std::result::Result<BTreeMap<Address, u64>, arith::Error> {
+        let mut counts: BTreeMap<Address, u64> = owners
+            .iter()
+            .filter(|owner| !owner.is_internal())
+            .map(|owner| {
+                let count = self
+                    .block_account_writes
+                    .get(owner)
+                    .copied()
+                    .unwrap_or_default();
+                (owner.clone(), count)
+            })
+            .collect();
+        for key in self
+            .batch_write_log
+            .iter()
+            .flat_map(|batch_log| batch_log.write_log.keys())
+            .chain(self.tx_write_log.write_log.keys())
+        {
+            if let Some(count) =
+                key.fst_address().and_then(|owner| counts.get_mut(owner))
+            {
+                *count = checked!(count + 1)?;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Get the distinct non-temporary storage keys modified in the current
+    /// block, including the current batch and transaction.
+    pub fn get_block_keys(&self) -> BTreeSet<&storage::Key> {
+        self.block_write_log
+            .keys()
+            .chain(
+                self.batch_write_log
+                    .iter()
+                    .flat_map(|batch_log| batch_log.write_log.keys()),
+            )
+            .chain(self.tx_write_log.write_log.keys())
+            .collect()
+    }
+
+    /// Compute a digest of the latest modification of every non-temporary key
+    /// in the block, batch and current transaction write logs. The keys are
+    /// hashed in sorted order, so the digest doesn't depend on the order in
+    /// which the modifications were made.
+    pub fn digest(&self) -> KeccakHash {
+        let mut modifications = BTreeMap::new();
+        // Later logs take precedence over earlier ones
+        for log in std::iter::once(&self.block_write_log)
+            .chain(self.batch_write_log.iter().map(|log| &log.write_log))
+            .chain(std::iter::once(&self.tx_write_log.write_log))
+        {
+            modifications.extend(log.iter());
+        }
+        keccak_hash(
+            modifications
+                .into_iter()
+                .collect::<Vec<_>>()
+                .serialize_to_vec(),
+        )
+    }
+
+    /// Apply the storage modifications of the block, batch and current
+    /// transaction write logs of `other` on top of the current transaction
+    /// write log, with the last modification of a key taking precedence. The
+    /// events of `other` are added to the events of the current transaction
+    /// and its established address generator, if any, becomes the generator
+    /// of the current transaction. The replay protection entries of `other`
+    /// are added to the current ones. Returns an error, without merging
+    /// anything, if `other` has temporary values, as these are local to a
+    /// transaction, if both write logs have generated addresses, as their
+    /// generators cannot be reconciled, if `other` has counted writes to
+    /// accounts' subspaces in its block write log, as these would be counted
+    /// again once the merged modifications are committed, or if a replay
+    /// protection entry of `other` is already present.
+    pub fn merge(&mut self, other: WriteLog) -> Result<()> {
+        if !other.tx_write_log.tx_temp_log.is_empty() {
+            return Err(Error::MergeTemporaryValue);
+        }
+        if !other.block_account_writes.is_empty() {
+            return Err(Error::MergeAccountWrites);
+        }
+        if let Some(hash) = other
+            .replay_protection
+            .iter()
+            .find(|hash| self.replay_protection.contains(*hash))
+        {
+            return Err(Error::ReplayProtection(format!(
+                "Requested a merge of hash {hash} which has already been \
+                 processed"
+            )));
+        }
+        let WriteLog {
+            block_address_gen,
+            block_write_log,
+            batch_write_log,
+            tx_write_log,
+            replay_protection,
+            ..
+        } = other;
+        let address_gen = tx_write_log
+            .address_gen
+            .or_else(|| {
+                batch_write_log
+                    .iter()
+                    .rev()
+                    .find_map(|log| log.address_gen.clone())
+            })
+            .or(block_address_gen);
+        if address_gen.is_some() && self.has_address_gen() {
+            return Err(Error::MergeAddressGen);
+        }
+        if address_gen.is_some() {
+            self.tx_write_log.address_gen = address_gen;
+        }
+        self.replay_protection.extend(replay_protection);
+        for log in std::iter::once(block_write_log)
+            .chain(batch_write_log.into_iter().map(|log| log.write_log))
+            .chain(std::iter::once(tx_write_log.write_log))
+        {
+            self.tx_write_log.write_log.extend(log);
+        }
+        for (event_type, events) in tx_write_log.events.tree {
+            match self.tx_write_log.events.tree.get_mut(&event_type) {
+                Some(event_set) => event_set.extend(events),
+                None => {
+                    self.tx_write_log.events.tree.insert(event_type, events);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if any of the block, batch or current transaction write logs has
+    /// generated established addresses.
+    fn has_address_gen(&self) -> bool {
+        self.block_address_gen.is_some()
+            || self
+                .batch_write_log
+                .iter()
+                .any(|log| log.address_gen.is_some())
+            || self.tx_write_log.address_gen.is_some()
+    }
+
     /// Get the addresses of accounts initialized in the current transaction.
     pub fn get_initialized_accounts(&self) -> Vec<Address> {
         self.tx_write_log
@@ -647,6 +1258,19 @@ impl WriteLog {
         self.batch_write_log.push(batched_log);
     }
 
+    /// Forget all the modifications of the current transaction whose storage
+    /// key matches the given prefix, including temporary values. Unlike
+    /// `delete_prefix`, this doesn't record any deletions - the matching keys
+    /// will read their values prior to the current transaction again.
+    pub fn clear_prefix(&mut self, prefix: &storage::Key) {
+        self.tx_write_log
+            .write_log
+            .retain(|key, _| key.split_prefix(prefix).is_none());
+        self.tx_write_log
+            .tx_temp_log
+            .retain(|key, _| key.split_prefix(prefix).is_none());
+    }
+
     /// Drop the current transaction's write log and IBC events when it's
     /// declined by any of the triggered validity predicates. Starts a new
     /// transaction write log and clears the temp write log.
@@ -665,6 +1289,16 @@ impl WriteLog {
     /// separately.
     pub fn commit_batch_only(&mut self) {
         for log in std::mem::take(&mut self.batch_write_log) {
+            for owner in log
+                .write_log
+                .keys()
+                .filter_map(storage::Key::fst_address)
+                .filter(|owner| !owner.is_internal())
+            {
+                let count =
+                    self.block_account_writes.entry(owner.clone()).or_default();
+                *count = count.saturating_add(1);
+            }
             self.block_write_log.extend(log.write_log);
             self.block_address_gen = log.address_gen;
         }
@@ -783,6 +1417,7 @@ impl WriteLog {
 mod tests {
     use assert_matches::assert_matches;
     use namada_core::address;
+    use namada_core::storage::KeySeg;
     use pretty_assertions::assert_eq;
     use proptest::prelude::*;
 
@@ -883,6 +1518,340 @@ mod tests {
         assert_eq!(diff, reinserted.len() as i64);
     }
 
+    #[test]
+    fn test_digest_independent_of_order() {
+        let key1 = storage::Key::parse("key1").unwrap();
+        let key2 = storage::Key::parse("key2").unwrap();
+        let key3 = storage::Key::parse("key3").unwrap();
+        let temp_key = storage::Key::parse("temp").unwrap();
+
+        let mut write_log_a = WriteLog::default();
+        let _ = write_log_a.write(&key1, vec![1]).unwrap();
+        let _ = write_log_a.write(&key2, vec![2]).unwrap();
+        write_log_a.commit_batch_and_current_tx();
+        let _ = write_log_a.delete(&key3).unwrap();
+
+        let mut write_log_b = WriteLog::default();
+        let _ = write_log_b.delete(&key3).unwrap();
+        let _ = write_log_b.write(&key2, vec![0]).unwrap();
+        let _ = write_log_b.write(&key2, vec![2]).unwrap();
+        let _ = write_log_b.write(&key1, vec![1]).unwrap();
+        // Temporary writes are not included
+        let _ = write_log_b.write_temp(&temp_key, vec![4]).unwrap();
+
+        assert_eq!(write_log_a.digest(), write_log_b.digest());
+
+        // A different value changes the digest
+        let _ = write_log_b.write(&key1, vec![5]).unwrap();
+        assert_ne!(write_log_a.digest(), write_log_b.digest());
+    }
+
+    #[test]
+    fn test_merge() {
+        let key1 = storage::Key::parse("key1").unwrap();
+        let key2 = storage::Key::parse("key2").unwrap();
+        let key3 = storage::Key::parse("key3").unwrap();
+
+        // Disjoint logs
+        let mut write_log = WriteLog::default();
+        let _ = write_log.write(&key1, vec![1]).unwrap();
+        let mut other = WriteLog::default();
+        let _ = other.write(&key2, vec![2]).unwrap();
+        other.commit_batch_and_current_tx();
+        let _ = other.delete(&key3).unwrap();
+        write_log.merge(other).unwrap();
+        assert_eq!(
+            write_log.read(&key1).unwrap().0,
+            Some(&StorageModification::Write { value: vec![1] })
+        );
+        assert_eq!(
+            write_log.read(&key2).unwrap().0,
+            Some(&StorageModification::Write { value: vec![2] })
+        );
+        assert_eq!(
+            write_log.read(&key3).unwrap().0,
+            Some(&StorageModification::Delete)
+        );
+
+        // Overlapping writes, the merged log wins
+        let mut other = WriteLog::default();
+        let _ = other.write(&key1, vec![3]).unwrap();
+        other.commit_batch_and_current_tx();
+        let _ = other.write(&key1, vec![4]).unwrap();
+        write_log.merge(other).unwrap();
+        assert_eq!(
+            write_log.read(&key1).unwrap().0,
+            Some(&StorageModification::Write { value: vec![4] })
+        );
+
+        // Temporary values are rejected
+        let mut other = WriteLog::default();
+        let _ = other.write(&key2, vec![5]).unwrap();
+        let _ = other
+            .write_temp(&storage::Key::parse("temp").unwrap(), vec![6])
+            .unwrap();
+        assert_matches!(
+            write_log.merge(other),
+            Err(Error::MergeTemporaryValue)
+        );
+        assert_eq!(
+            write_log.read(&key2).unwrap().0,
+            Some(&StorageModification::Write { value: vec![2] })
+        );
+    }
+
+    #[test]
+    fn test_merge_replay_protection_and_account_writes() {
+        let hash = Hash::sha256(b"tx");
+        let other_hash = Hash::sha256(b"other tx");
+
+        // The replay protection entries are merged
+        let mut write_log = WriteLog::default();
+        write_log.write_tx_hash(hash).unwrap();
+        let mut other = WriteLog::default();
+        other.write_tx_hash(other_hash).unwrap();
+        write_log.merge(other).unwrap();
+        assert!(write_log.has_replay_protection_entry(&hash));
+        assert!(write_log.has_replay_protection_entry(&other_hash));
+
+        // An entry that's already present is rejected
+        let mut other = WriteLog::default();
+        other.write_tx_hash(hash).unwrap();
+        assert_matches!(
+            write_log.merge(other),
+            Err(Error::ReplayProtection(_))
+        );
+
+        // Committed writes to an account's subspace are rejected
+        let key = storage::Key::from(
+            address::testing::established_address_1().to_db_key(),
+        )
+        .push(&"key".to_string())
+        .unwrap();
+        let mut other = WriteLog::default();
+        let _ = other.write(&key, vec![1]).unwrap();
+        other.commit_batch_and_current_tx();
+        assert_matches!(write_log.merge(other), Err(Error::MergeAccountWrites));
+        assert_eq!(write_log.read(&key).unwrap().0, None);
+    }
+
+    #[test]
+    fn test_merge_events_and_address_gen() {
+        let address_gen = EstablishedAddressGen::new("test");
+        let vp_hash = Hash::sha256(b"vp");
+        let new_event = |event_type: &str| {
+            Event::new(
+                namada_events::EventTypeBuilder::new_with_type(event_type)
+                    .build(),
+                namada_events::EventLevel::Tx,
+            )
+        };
+
+        // The events are merged with the events of the current tx
+        let mut write_log = WriteLog::default();
+        let _ = write_log.emit_event(new_event("tx"));
+        let mut other = WriteLog::default();
+        let _ = other.emit_event(new_event("other"));
+        let (addr, _) = other.init_account(&address_gen, vp_hash, &[]);
+        write_log.merge(other).unwrap();
+        assert_eq!(write_log.take_events().len(), 2);
+
+        // The generator of the merged log continues from its addresses
+        let (next_addr, _) = write_log.init_account(&address_gen, vp_hash, &[]);
+        assert_ne!(next_addr, addr);
+
+        // Logs that both generated addresses are rejected
+        let mut other = WriteLog::default();
+        let _ = other.init_account(&address_gen, vp_hash, &[]);
+        let _ = other.emit_event(new_event("rejected"));
+        assert_matches!(write_log.merge(other), Err(Error::MergeAddressGen));
+        assert!(write_log.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_changed_keys_by_subsystem() {
+        let pos_key = storage::Key::from(
+            Address::Internal(InternalAddress::PoS).to_db_key(),
+        )
+        .push(&"bond".to_string())
+        .unwrap();
+        let gov_key = storage::Key::from(
+            Address::Internal(InternalAddress::Governance).to_db_key(),
+        )
+        .push(&"proposal".to_string())
+        .unwrap();
+        let user_key = storage::Key::from(
+            address::testing::established_address_1().to_db_key(),
+        )
+        .push(&"balance".to_string())
+        .unwrap();
+        let plain_key = storage::Key::parse("plain").unwrap();
+
+        let mut write_log = WriteLog::default();
+        for key in [&pos_key, &gov_key, &user_key, &plain_key] {
+            let _ = write_log.write(key, vec![1]).unwrap();
+        }
+        let grouped = write_log.changed_keys_by_subsystem();
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped[&Some(InternalAddress::PoS)], vec![pos_key]);
+        assert_eq!(grouped[&Some(InternalAddress::Governance)], vec![gov_key]);
+        let mut user_keys = vec![user_key, plain_key];
+        user_keys.sort();
+        assert_eq!(grouped[&None], user_keys);
+    }
+
+    #[test]
+    fn test_validate_keys() {
+        let valid_key = storage::Key::parse("valid/key").unwrap();
+        let illegal_key = storage::Key::parse("illegal\nkey").unwrap();
+        // A string segment containing the separator is split when parsed
+        let colliding_key = storage::Key {
+            segments: vec![storage::DbKeySeg::StringSeg(
+                "colliding/key".to_string(),
+            )],
+        };
+
+        let mut write_log = WriteLog::default();
+        let _ = write_log.write(&valid_key, vec![1]).unwrap();
+        assert_eq!(write_log.validate_keys(), Ok(()));
+
+        write_log.protocol_write(&illegal_key, vec![2]).unwrap();
+        let _ = write_log.delete(&colliding_key).unwrap();
+        assert_eq!(
+            write_log.validate_keys(),
+            Err(vec![
+                KeyError::NoRoundTrip(colliding_key),
+                KeyError::IllegalChar {
+                    key: illegal_key,
+                    illegal: '\n',
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_write_batch() {
+        let key_1 = storage::Key::parse("key1").unwrap();
+        let key_2 = storage::Key::parse("key2").unwrap();
+
+        let mut write_log = WriteLog::default();
+        let (gas, size_diff) = write_log
+            .write_batch(&[(key_1.clone(), vec![1]), (key_2.clone(), vec![2])])
+            .unwrap();
+        // The same as the costs of the individual writes
+        let (gas_1, size_diff_1) =
+            WriteLog::default().write(&key_1, vec![1]).unwrap();
+        let (gas_2, size_diff_2) =
+            WriteLog::default().write(&key_2, vec![2]).unwrap();
+        assert_eq!(gas, gas_1.checked_add(gas_2).unwrap());
+        assert_eq!(size_diff, size_diff_1 + size_diff_2);
+        assert_eq!(
+            write_log.read(&key_1).unwrap().0,
+            Some(&StorageModification::Write { value: vec![1] })
+        );
+        assert_eq!(
+            write_log.read(&key_2).unwrap().0,
+            Some(&StorageModification::Write { value: vec![2] })
+        );
+    }
+
+    #[test]
+    fn test_write_batch_rollback() {
+        let key_1 = storage::Key::parse("key1").unwrap();
+        let key_2 = storage::Key::parse("key2").unwrap();
+        let temp_key = storage::Key::parse("temp").unwrap();
+
+        let mut write_log = WriteLog::default();
+        let _ = write_log.write(&key_1, vec![1]).unwrap();
+        // A temporary value cannot be overwritten by a write
+        let _ = write_log.write_temp(&temp_key, vec![0]).unwrap();
+
+        let result = write_log.write_batch(&[
+            (key_1.clone(), vec![10]),
+            (key_2.clone(), vec![20]),
+            (temp_key.clone(), vec![30]),
+        ]);
+        assert_matches!(result, Err(Error::UpdateTemporaryValue));
+
+        // The writes before the failing one are rolled back
+        assert_eq!(
+            write_log.read(&key_1).unwrap().0,
+            Some(&StorageModification::Write { value: vec![1] })
+        );
+        assert_eq!(write_log.read(&key_2).unwrap().0, None);
+        assert_eq!(write_log.read_temp(&temp_key).unwrap().0, Some(&vec![0]));
+    }
+
+    #[test]
+    fn test_mod_source() {
+        let tx_key = storage::Key::parse("tx").unwrap();
+        let vp_key = storage::Key::parse("vp").unwrap();
+        let protocol_key = storage::Key::parse("protocol").unwrap();
+
+        let mut write_log = WriteLog::default();
+        let _ = write_log.write(&tx_key, vec![1]).unwrap();
+        write_log.set_mod_source(ModSource::NativeVp(InternalAddress::Ibc));
+        let _ = write_log.delete(&vp_key).unwrap();
+        write_log.protocol_write(&protocol_key, vec![2]).unwrap();
+
+        assert_eq!(write_log.mod_source(&tx_key), Some(&ModSource::Tx));
+        assert_eq!(
+            write_log.mod_source(&vp_key),
+            Some(&ModSource::NativeVp(InternalAddress::Ibc))
+        );
+        assert_eq!(
+            write_log.mod_source(&protocol_key),
+            Some(&ModSource::Protocol)
+        );
+        assert_eq!(
+            write_log.mod_source(&storage::Key::parse("other").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_modification_at() {
+        let key = storage::Key::parse("key").unwrap();
+
+        let mut write_log = WriteLog::default();
+        let _ = write_log.write(&key, vec![1]).unwrap();
+        let _ = write_log.write(&key, vec![2]).unwrap();
+        let _ = write_log.delete(&key).unwrap();
+
+        assert_eq!(
+            write_log.modification_at(&key, 0),
+            Some(&StorageModification::Write { value: vec![1] })
+        );
+        assert_eq!(
+            write_log.modification_at(&key, 1),
+            Some(&StorageModification::Write { value: vec![2] })
+        );
+        assert_eq!(
+            write_log.modification_at(&key, 2),
+            Some(&StorageModification::Delete)
+        );
+        assert_eq!(write_log.modification_at(&key, 3), None);
+        assert_eq!(
+            write_log
+                .modification_at(&storage::Key::parse("other").unwrap(), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mod_tracking_cleared_on_commit_block() {
+        let mut state = crate::testing::TestState::default();
+        let key = storage::Key::parse("key").unwrap();
+        let _ = state.write_log_mut().write(&key, vec![1]).unwrap();
+        state.write_log_mut().commit_batch_and_current_tx();
+        assert!(state.write_log().modification_at(&key, 0).is_some());
+        assert!(state.write_log().mod_source(&key).is_some());
+
+        state.commit_block().unwrap();
+        assert_eq!(state.write_log().modification_at(&key, 0), None);
+        assert_eq!(state.write_log().mod_source(&key), None);
+    }
+
     #[test]
     fn test_crud_account() {
         let mut write_log = WriteLog::default();
@@ -1023,6 +1992,57 @@ mod tests {
         assert_eq!(value, None);
     }
 
+    #[test]
+    fn test_serialize_round_trip() {
+        let mut write_log = WriteLog::default();
+        let address_gen = EstablishedAddressGen::new("test");
+        let val = "val".as_bytes().to_vec();
+
+        // a block-level write, delete and initialized account
+        let block_key =
+            storage::Key::parse("block").expect("cannot parse the key string");
+        let deleted_key = storage::Key::parse("deleted")
+            .expect("cannot parse the key string");
+        let _ = write_log.write(&block_key, val.clone()).unwrap();
+        let _ = write_log.delete(&deleted_key).unwrap();
+        let _ = write_log.init_account(
+            &address_gen,
+            Hash::sha256("vp".as_bytes()),
+            &[],
+        );
+        write_log.commit_batch_and_current_tx();
+        write_log
+            .write_tx_hash(Hash::sha256("tx".as_bytes()))
+            .unwrap();
+
+        // a batched tx
+        let batch_key =
+            storage::Key::parse("batch").expect("cannot parse the key string");
+        let _ = write_log.write(&batch_key, val.clone()).unwrap();
+        write_log.commit_tx_to_batch();
+
+        // the current tx with a temp value and an event
+        let tx_key =
+            storage::Key::parse("tx").expect("cannot parse the key string");
+        let temp_key =
+            storage::Key::parse("temp").expect("cannot parse the key string");
+        let _ = write_log.write(&tx_key, val.clone()).unwrap();
+        let _ = write_log.write_temp(&temp_key, val).unwrap();
+        let _ = write_log.emit_event(Event::new(
+            namada_events::EventTypeBuilder::new_with_type("test").build(),
+            namada_events::EventLevel::Tx,
+        ));
+
+        let bytes = write_log.to_bytes();
+        let restored = WriteLog::from_bytes(&bytes).expect("decoding failed");
+        assert_eq!(restored, write_log);
+
+        assert_matches!(
+            WriteLog::from_bytes(&bytes[1..]),
+            Err(Error::Decode(_))
+        );
+    }
+
     #[test]
     fn test_replay_protection_commit() {
         let mut state = crate::testing::TestState::default();
@@ -1158,6 +2178,48 @@ mod tests {
         ));
     }
 
+    // Test that clearing a prefix forgets the tx modifications under it, so
+    // that the keys read their committed values again
+    #[test]
+    fn test_clear_prefix() {
+        use namada_storage::StorageRead;
+
+        let mut state = crate::testing::TestState::default();
+
+        let prefix = storage::Key::parse("subsystem").unwrap();
+        let key1 = prefix.push(&"key1".to_string()).unwrap();
+        let key2 = prefix.push(&"key2".to_string()).unwrap();
+        let key3 = prefix.push(&"key3".to_string()).unwrap();
+        let other =
+            storage::Key::parse("other").expect("cannot parse the key string");
+
+        // commit some values to storage
+        let committed = "committed".as_bytes().to_vec();
+        let _ = state.write_log.write(&key1, committed.clone()).unwrap();
+        let _ = state.write_log.write(&key2, committed.clone()).unwrap();
+        state.write_log.commit_batch_and_current_tx();
+        state.commit_block().expect("commit failed");
+
+        // modify the keys in the current tx
+        let updated = "updated".as_bytes().to_vec();
+        let _ = state.write_log.write(&key1, updated.clone()).unwrap();
+        let _ = state.write_log.delete(&key2).unwrap();
+        let _ = state.write_log.write_temp(&key3, updated.clone()).unwrap();
+        let _ = state.write_log.write(&other, updated.clone()).unwrap();
+
+        state.write_log.clear_prefix(&prefix);
+
+        // the cleared keys read their committed values again
+        assert_eq!(state.read_bytes(&key1).unwrap(), Some(committed.clone()));
+        assert_eq!(state.read_bytes(&key2).unwrap(), Some(committed));
+        assert_eq!(state.write_log.read_temp(&key3).unwrap().0, None);
+        assert!(state.write_log.read(&key1).unwrap().0.is_none());
+        assert!(state.write_log.read(&key2).unwrap().0.is_none());
+
+        // the modifications outside of the prefix are preserved
+        assert_eq!(state.read_bytes(&other).unwrap(), Some(updated));
+    }
+
     prop_compose! {
         fn arb_verifiers_changed_key_tx_all_key()
             (verifiers_from_tx in testing::arb_verifiers_from_tx())
@@ -367,6 +367,20 @@ pub trait DBIter<'iter> {
     /// ordered by the storage keys.
     fn iter_prefix(&'iter self, prefix: Option<&Key>) -> Self::PrefixIter;
 
+    /// WARNING: This only works for values that have been committed to DB.
+    ///
+    /// Like [`DBIter::iter_prefix`], but hint the DB to prefetch
+    /// `batch_size` bytes at a time while iterating. Larger batches trade
+    /// memory for fewer reads from disk when iterating large prefixes. DBs
+    /// that don't prefetch ignore the hint, which is also the default.
+    fn iter_prefix_with_batch(
+        &'iter self,
+        prefix: Option<&Key>,
+        _batch_size: usize,
+    ) -> Self::PrefixIter {
+        self.iter_prefix(prefix)
+    }
+
     /// WARNING: This only works for values that have been committed to DB.
     /// To be able to see values written or deleted, but not yet committed,
     /// use the `StorageWithWriteLog`.
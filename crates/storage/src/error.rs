@@ -5,6 +5,7 @@ use std::convert::Infallible;
 use std::num::TryFromIntError;
 
 use namada_core::arith;
+use namada_core::storage::Key;
 use thiserror::Error;
 
 use crate::db;
@@ -20,6 +21,10 @@ pub enum Error {
     Custom(CustomError),
     #[error("{0}: {1}")]
     CustomWithMessage(&'static str, CustomError),
+    #[error("The required key {key} was not found in storage")]
+    KeyNotFound { key: Key },
+    #[error("Cannot modify the key {key} through a read-only storage")]
+    ReadOnly { key: Key },
 }
 
 /// Result of a storage API call.
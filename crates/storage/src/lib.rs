@@ -29,19 +29,39 @@ pub mod conversion_state;
 mod db;
 mod error;
 pub mod mockdb;
+mod read_only;
 pub mod tx_queue;
 pub mod types;
 
 pub use db::{Error as DbError, Result as DbResult, *};
 pub use error::{CustomError, Error, OptionExt, Result, ResultExt};
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
 use namada_core::chain::ChainId;
 pub use namada_core::chain::{
     BlockHash, BlockHeader, BlockHeight, Epoch, Epochs,
 };
+use namada_core::collections::HashMap;
 pub use namada_core::hash::{Hash, StorageHasher};
 pub use namada_core::storage::*;
+pub use read_only::ReadOnlyStorage;
+
+/// The state of a key in storage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyState {
+    /// The key is present with the given value, which may be empty
+    Present(Vec<u8>),
+    /// The key has never been written or it has been deleted
+    Absent,
+}
+
+impl KeyState {
+    /// Check if the key is present
+    pub fn is_present(&self) -> bool {
+        matches!(self, Self::Present(_))
+    }
+}
 
 /// Common storage read interface
 pub trait StorageRead {
@@ -66,6 +86,15 @@ pub trait StorageRead {
     /// Storage read raw bytes. It will try to read from the storage.
     fn read_bytes(&self, key: &Key) -> Result<Option<Vec<u8>>>;
 
+    /// Storage read the state of a key. Unlike [`StorageRead::read`], an
+    /// empty value is not mistaken for an absent key.
+    fn read_state(&self, key: &Key) -> Result<KeyState> {
+        Ok(match self.read_bytes(key)? {
+            Some(value) => KeyState::Present(value),
+            None => KeyState::Absent,
+        })
+    }
+
     /// Storage `has_key` in. It will try to read from the storage.
     fn has_key(&self, key: &Key) -> Result<bool>;
 
@@ -85,6 +114,81 @@ pub trait StorageRead {
         iter: &mut Self::PrefixIter<'iter>,
     ) -> Result<Option<(String, Vec<u8>)>>;
 
+    /// Read all the Borsh encoded values under the given prefix into a map,
+    /// keyed by their storage key relative to the prefix (e.g. the owner
+    /// segment of a balance sub-key). A value stored at the prefix key itself
+    /// is skipped.
+    fn read_map<T: BorshDeserialize>(
+        &self,
+        prefix: &Key,
+    ) -> Result<HashMap<String, T>>
+    where
+        Self: Sized,
+    {
+        let mut map = HashMap::new();
+        for item in iter_prefix(self, prefix)? {
+            let (key, val) = item?;
+            if let Some(Some(suffix)) = key.split_prefix(prefix) {
+                map.insert(suffix.to_string(), val);
+            }
+        }
+        Ok(map)
+    }
+
+    /// Read the raw bytes of a value that must be present in storage and
+    /// check that their SHA-256 hash matches the `expected` hash (e.g. to
+    /// confirm that a stored code matches a governance-approved hash).
+    fn read_checked(&self, key: &Key, expected: &Hash) -> Result<Vec<u8>> {
+        let bytes = self
+            .read_bytes(key)?
+            .ok_or_else(|| Error::KeyNotFound { key: key.clone() })?;
+        let hash = Hash::sha256(&bytes);
+        if hash != *expected {
+            return Err(Error::new_alloc(format!(
+                "The hash {hash} of the value at key {key} doesn't match the \
+                 expected hash {expected}"
+            )));
+        }
+        Ok(bytes)
+    }
+
+    /// Read the height of the block in which the value of the given key was
+    /// last written. This is only tracked for keys whose diffs are persisted.
+    /// Values written in the current block are reported with the current
+    /// block height. Returns `None` if the key is not present or if its last
+    /// write is not tracked. Storages that don't track the modifications of
+    /// keys always return `None`.
+    fn read_last_modified_height(
+        &self,
+        _key: &Key,
+    ) -> Result<Option<BlockHeight>> {
+        Ok(None)
+    }
+
+    /// Read the raw bytes of a value together with the epoch in which it was
+    /// last written. Like [`StorageRead::read_last_modified_height`], this is
+    /// only tracked for keys whose diffs are persisted. Values written in the
+    /// current block are reported with the current epoch. Returns `None` if
+    /// the key is not present or if its last write is not tracked.
+    fn read_bytes_with_epoch(
+        &self,
+        key: &Key,
+    ) -> Result<Option<(Vec<u8>, Epoch)>> {
+        let Some(value) = self.read_bytes(key)? else {
+            return Ok(None);
+        };
+        let Some(height) = self.read_last_modified_height(key)? else {
+            return Ok(None);
+        };
+        let epoch = self.get_epoch_at_height(height)?.ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Cannot find the epoch of the block {height} in which the key \
+                 {key} was last written"
+            ))
+        })?;
+        Ok(Some((value, epoch)))
+    }
+
     /// Getting the chain ID.
     fn get_chain_id(&self) -> Result<ChainId>;
 
@@ -154,6 +258,20 @@ pub trait StorageWrite {
     /// Delete a value at the given key from storage.
     fn delete(&mut self, key: &Key) -> Result<()>;
 
+    /// Write a value to be encoded with Borsh at the given key to storage if
+    /// it's `Some`, otherwise delete the key, such that reading it back with
+    /// [`StorageRead::read`] gives the same `Option`.
+    fn write_option<T: BorshSerialize>(
+        &mut self,
+        key: &Key,
+        val: Option<&T>,
+    ) -> Result<()> {
+        match val {
+            Some(val) => self.write(key, val),
+            None => self.delete(key),
+        }
+    }
+
     /// Delete all key-vals with a matching prefix.
     fn delete_prefix(&mut self, prefix: &Key) -> Result<()>
     where
@@ -173,16 +291,268 @@ pub trait StorageWrite {
         }
         Ok(())
     }
+
+    /// Increment the `u64` value at the given key by the given amount and
+    /// return the new value. A missing value is treated as `0`. Errors on
+    /// overflow.
+    fn increment(&mut self, key: &Key, by: u64) -> Result<u64>
+    where
+        Self: StorageRead + Sized,
+    {
+        let current: u64 = self.read(key)?.unwrap_or_default();
+        let new = checked!(current + by)?;
+        self.write(key, new)?;
+        Ok(new)
+    }
+
+    /// Decrement the `u64` value at the given key by the given amount and
+    /// return the new value. A missing value is treated as `0`. Errors on
+    /// underflow.
+    fn decrement(&mut self, key: &Key, by: u64) -> Result<u64>
+    where
+        Self: StorageRead + Sized,
+    {
+        let current: u64 = self.read(key)?.unwrap_or_default();
+        let new = checked!(current - by)?;
+        self.write(key, new)?;
+        Ok(new)
+    }
 }
 
-/// Iterate items matching the given prefix, ordered by the storage keys.
-pub fn iter_prefix_bytes<'a>(
-    storage: &'a impl StorageRead,
-    prefix: &Key,
-) -> Result<impl Iterator<Item = Result<(Key, Vec<u8>)>> + 'a> {
-    let mut iter = storage.iter_prefix(prefix)?;
-    let iter = std::iter::from_fn(move || {
-        match storage.iter_next(&mut iter) {
+/// Read a value that must be present in storage. Returns
+/// [`Error::KeyNotFound`] naming the key if it's absent.
+pub fn read_required<T, S>(storage: &S, key: &Key) -> Result<T>
+where
+    T: BorshDeserialize,
+    S: StorageRead + ?Sized,
+{
+    storage
+        .read(key)?
+        .ok_or_else(|| Error::KeyNotFound { key: key.clone() })
+}
+
+/// Move the value stored under `from` to `to`, overwriting any value at `to`,
+/// and delete `from`. Returns whether a value was moved. Moving a key onto
+/// itself is a no-op.
+pub fn move_value<S>(storage: &mut S, from: &Key, to: &Key) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    if from == to {
+        return Ok(false);
+    }
+    match storage.read_bytes(from)? {
+        Some(value) => {
+            storage.write_bytes(to, value)?;
+            storage.delete(from)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Copy every value stored under the `from_prefix` to the same key relative to
+/// the `to_prefix`, overwriting any existing values. The source values are
+/// kept. Returns the number of copied values. The matching keys are collected
+/// before any write, so the prefixes may overlap.
+pub fn copy_prefix<S>(
+    storage: &mut S,
+    from_prefix: &Key,
+    to_prefix: &Key,
+) -> Result<u64>
+where
+    S: StorageRead + StorageWrite,
+{
+    let items = iter_prefix_bytes(storage, from_prefix)?
+        .collect::<Result<Vec<(Key, Vec<u8>)>>>()?;
+    let mut copied = 0_u64;
+    for (key, value) in items {
+        let target = match key.split_prefix(from_prefix) {
+            Some(Some(suffix)) => to_prefix.join(&suffix),
+            Some(None) => to_prefix.clone(),
+            None => continue,
+        };
+        storage.write_bytes(&target, value)?;
+        copied = checked!(copied + 1)?;
+    }
+    Ok(copied)
+}
+
+/// Delete the key only if its current value, taking into account any
+/// uncommitted changes, is equal to the `expected` bytes. Returns whether the
+/// key was deleted.
+pub fn delete_if_equals<S>(
+    storage: &mut S,
+    key: &Key,
+    expected: &[u8],
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    match storage.read_bytes(key)? {
+        Some(value) if value == expected => {
+            storage.delete(key)?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Write the value only if the key is absent, taking into account any
+/// uncommitted changes. Returns whether the value was written.
+pub fn init_once<S, T>(storage: &mut S, key: &Key, val: &T) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+    T: BorshSerialize,
+{
+    if storage.has_key(key)? {
+        return Ok(false);
+    }
+    storage.write(key, val)?;
+    Ok(true)
+}
+
+/// A one-byte tag identifying the type of a value written with
+/// [`write_tagged`]. Each implementing type must have a distinct tag.
+pub trait TypeTag {
+    /// The type tag
+    const TYPE_TAG: u8;
+}
+
+impl TypeTag for bool {
+    const TYPE_TAG: u8 = 0;
+}
+
+impl TypeTag for u64 {
+    const TYPE_TAG: u8 = 1;
+}
+
+impl TypeTag for String {
+    const TYPE_TAG: u8 = 2;
+}
+
+impl TypeTag for Address {
+    const TYPE_TAG: u8 = 3;
+}
+
+impl TypeTag for Epoch {
+    const TYPE_TAG: u8 = 4;
+}
+
+impl TypeTag for BlockHeight {
+    const TYPE_TAG: u8 = 5;
+}
+
+/// Write a Borsh encoded value prefixed with the [`TypeTag`] of its type, to be
+/// read back with [`read_tagged`].
+pub fn write_tagged<S, T>(storage: &mut S, key: &Key, val: &T) -> Result<()>
+where
+    S: StorageWrite,
+    T: BorshSerialize + TypeTag,
+{
+    let mut bytes = vec![T::TYPE_TAG];
+    bytes.extend(val.serialize_to_vec());
+    storage.write_bytes(key, bytes)
+}
+
+/// Read a value written with [`write_tagged`]. Returns an error if the stored
+/// type tag doesn't match the [`TypeTag`] of `T`.
+pub fn read_tagged<S, T>(storage: &S, key: &Key) -> Result<Option<T>>
+where
+    S: StorageRead + ?Sized,
+    T: BorshDeserialize + TypeTag,
+{
+    let Some(bytes) = storage.read_bytes(key)? else {
+        return Ok(None);
+    };
+    match bytes.split_first() {
+        Some((&tag, val)) if tag == T::TYPE_TAG => {
+            T::try_from_slice(val).into_storage_result().map(Some)
+        }
+        Some((&tag, _)) => Err(Error::new_alloc(format!(
+            "Type tag mismatch for the key {key}: expected {}, found {tag}",
+            T::TYPE_TAG
+        ))),
+        None => Err(Error::new_alloc(format!(
+            "Missing type tag for the key {key}"
+        ))),
+    }
+}
+
+/// Write a `u64` value that must never decrease, such as a nonce or a height.
+/// Writing a value equal to the current one is allowed. Returns an error,
+/// without writing, if `new` is less than the current value, taking into
+/// account any uncommitted changes. An absent key is treated as `0`.
+pub fn write_monotonic_u64<S>(
+    storage: &mut S,
+    key: &Key,
+    new: u64,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if let Some(current) = storage.read::<u64>(key)? {
+        if new < current {
+            return Err(Error::new_alloc(format!(
+                "Value of the key {key} must not decrease from {current} to \
+                 {new}"
+            )));
+        }
+    }
+    storage.write(key, new)
+}
+
+/// Write the value only if the current block height is at least
+/// `min_height`. Returns whether the value was written.
+pub fn write_if_height_at_least<S, T>(
+    storage: &mut S,
+    key: &Key,
+    val: T,
+    min_height: BlockHeight,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+    T: BorshSerialize,
+{
+    if storage.get_block_height()? < min_height {
+        return Ok(false);
+    }
+    storage.write(key, val)?;
+    Ok(true)
+}
+
+/// An iterator over the raw items matching a prefix, ordered by the storage
+/// keys, as produced by [`StorageRead::iter_prefix`]. Unlike driving
+/// [`StorageRead::iter_next`] manually, the keys are parsed and any errors are
+/// propagated into the iterator's `Item`, so that the standard iterator
+/// combinators can be used.
+pub struct MergedPrefixIter<'iter, S>
+where
+    S: StorageRead + ?Sized,
+{
+    storage: &'iter S,
+    iter: S::PrefixIter<'iter>,
+}
+
+impl<'iter, S> MergedPrefixIter<'iter, S>
+where
+    S: StorageRead + ?Sized + 'iter,
+{
+    /// Iterate the items of the given `storage` matching the `prefix`.
+    pub fn new(storage: &'iter S, prefix: &Key) -> Result<Self> {
+        let iter = storage.iter_prefix(prefix)?;
+        Ok(Self { storage, iter })
+    }
+}
+
+impl<'iter, S> Iterator for MergedPrefixIter<'iter, S>
+where
+    S: StorageRead + ?Sized + 'iter,
+{
+    type Item = Result<(Key, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.storage.iter_next(&mut self.iter) {
             Ok(Some((key, val))) => {
                 let key = match Key::parse(key).into_storage_result() {
                     Ok(key) => key,
@@ -199,8 +569,31 @@ pub fn iter_prefix_bytes<'a>(
                 Some(Err(err))
             }
         }
-    });
-    Ok(iter)
+    }
+}
+
+/// Iterate items matching the given prefix, ordered by the storage keys.
+pub fn iter_prefix_bytes<'a, S>(
+    storage: &'a S,
+    prefix: &Key,
+) -> Result<MergedPrefixIter<'a, S>>
+where
+    S: StorageRead,
+{
+    MergedPrefixIter::new(storage, prefix)
+}
+
+/// Iterate the keys of the items matching the given prefix, ordered by the
+/// storage keys. Deleted keys are excluded. The values are not decoded.
+pub fn iter_prefix_keys<'a, S>(
+    storage: &'a S,
+    prefix: &Key,
+) -> Result<impl Iterator<Item = Result<Key>> + 'a>
+where
+    S: StorageRead,
+{
+    Ok(MergedPrefixIter::new(storage, prefix)?
+        .map(|item| item.map(|(key, _val)| key)))
 }
 
 /// Iterate Borsh encoded items matching the given prefix, ordered by the
@@ -360,6 +753,25 @@ where
     Ok(iter)
 }
 
+/// Returns `true` if the key is in user space, i.e. it's not owned by an
+/// internal address of one of the protocol's subsystems.
+pub fn is_user_key(key: &Key) -> bool {
+    !key.fst_address().is_some_and(Address::is_internal)
+}
+
+/// Iterate Borsh encoded items matching the given prefix, skipping the keys
+/// owned by internal addresses (see [`is_user_key`]), ordered by the storage
+/// keys.
+pub fn iter_prefix_user<'a, T>(
+    storage: &'a impl StorageRead,
+    prefix: &Key,
+) -> Result<impl Iterator<Item = Result<(Key, T)>> + 'a>
+where
+    T: BorshDeserialize,
+{
+    iter_prefix_with_filter(storage, prefix, is_user_key)
+}
+
 /// Helpers for testing components that depend on storage
 #[cfg(any(test, feature = "testing"))]
 pub mod testing {
@@ -50,7 +50,7 @@ const OLD_DIFF_PREFIX: &str = "old";
 const NEW_DIFF_PREFIX: &str = "new";
 
 /// An in-memory DB for testing.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct MockDB(
     // The state is wrapped in `RefCell` to allow modifying it directly from
     // batch write method (which requires immutable self ref).
@@ -0,0 +1,93 @@
+//! A read-only view of a storage.
+
+use namada_core::address::Address;
+use namada_core::chain::{BlockHeader, BlockHeight, ChainId, Epoch, Epochs};
+use namada_core::storage::{Key, TxIndex};
+
+use crate::{Error, Result, StorageRead, StorageWrite};
+
+/// A wrapper of a storage that can only be read. Any write or delete through
+/// it fails with [`Error::ReadOnly`], so that contexts where writes must be
+/// impossible (e.g. validity predicates) catch accidental state mutations.
+#[derive(Debug)]
+pub struct ReadOnlyStorage<'a, S>(pub &'a S);
+
+impl<S> StorageRead for ReadOnlyStorage<'_, S>
+where
+    S: StorageRead,
+{
+    type PrefixIter<'iter>
+        = S::PrefixIter<'iter>
+    where
+        Self: 'iter;
+
+    fn read_bytes(&self, key: &Key) -> Result<Option<Vec<u8>>> {
+        self.0.read_bytes(key)
+    }
+
+    fn has_key(&self, key: &Key) -> Result<bool> {
+        self.0.has_key(key)
+    }
+
+    fn iter_prefix<'iter>(
+        &'iter self,
+        prefix: &Key,
+    ) -> Result<Self::PrefixIter<'iter>> {
+        self.0.iter_prefix(prefix)
+    }
+
+    fn iter_next<'iter>(
+        &'iter self,
+        iter: &mut Self::PrefixIter<'iter>,
+    ) -> Result<Option<(String, Vec<u8>)>> {
+        self.0.iter_next(iter)
+    }
+
+    fn read_last_modified_height(
+        &self,
+        key: &Key,
+    ) -> Result<Option<BlockHeight>> {
+        self.0.read_last_modified_height(key)
+    }
+
+    fn get_chain_id(&self) -> Result<ChainId> {
+        self.0.get_chain_id()
+    }
+
+    fn get_block_height(&self) -> Result<BlockHeight> {
+        self.0.get_block_height()
+    }
+
+    fn get_block_header(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<BlockHeader>> {
+        self.0.get_block_header(height)
+    }
+
+    fn get_block_epoch(&self) -> Result<Epoch> {
+        self.0.get_block_epoch()
+    }
+
+    fn get_pred_epochs(&self) -> Result<Epochs> {
+        self.0.get_pred_epochs()
+    }
+
+    fn get_tx_index(&self) -> Result<TxIndex> {
+        self.0.get_tx_index()
+    }
+
+    fn get_native_token(&self) -> Result<Address> {
+        self.0.get_native_token()
+    }
+}
+
+impl<S> StorageWrite for ReadOnlyStorage<'_, S> {
+    fn write_bytes(&mut self, key: &Key, _val: impl AsRef<[u8]>) -> Result<()> {
+        Err(Error::ReadOnly { key: key.clone() })
+    }
+
+    fn delete(&mut self, key: &Key) -> Result<()> {
+        Err(Error::ReadOnly { key: key.clone() })
+    }
+}
@@ -3,15 +3,32 @@
 pub mod ibc;
 pub mod tx_data;
 
+use std::collections::BTreeMap;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use namada_core::hash::Hash;
 use strum::EnumIter;
+use thiserror::Error;
 
 /// Path from the root of the Git repo to the directory under which built test
 /// wasms can be found.
 pub const WASM_FOR_TESTS_DIR: &str = "wasm_for_tests";
 
+/// Name of the manifest file with the expected SHA-256 checksums of the test
+/// wasms, found under [`WASM_FOR_TESTS_DIR`].
+pub const WASM_CHECKSUMS_FILE: &str = "checksums.json";
+
+/// Errors from verifying the integrity of a test wasm
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ChecksumError {
+    #[error("No expected checksum found for the wasm")]
+    Missing,
+    #[error("Wasm checksum mismatch, expected {expected}, got {actual}")]
+    Mismatch { expected: Hash, actual: Hash },
+}
+
 /// Corresponds to wasms that we build for tests, under [`WASM_FOR_TESTS_DIR`].
 /// See the `wasm_for_tests/wasm_source` crate for documentation on what these
 /// wasms do.
@@ -46,7 +63,12 @@ impl TestWasms {
     /// Get the path to where this test wasm is expected to be, or panic if not
     /// able to.
     pub fn path(&self) -> PathBuf {
-        let filename = match self {
+        wasm_for_tests_dir().join(self.filename())
+    }
+
+    /// Get the file name of this test wasm.
+    pub fn filename(&self) -> &'static str {
+        match self {
             TestWasms::TxFail => "tx_fail.wasm",
             TestWasms::TxFailEvent => "tx_fail_event.wasm",
             TestWasms::TxMemoryLimit => "tx_memory_limit.wasm",
@@ -71,23 +93,19 @@ impl TestWasms {
             TestWasms::VpMemoryLimit => "vp_memory_limit.wasm",
             TestWasms::VpReadStorageKey => "vp_read_storage_key.wasm",
             TestWasms::VpVerifySignature => "vp_verify_signature.wasm",
-        };
-        let cwd =
-            env::current_dir().expect("Couldn't get current working directory");
-        // crudely find the root of the repo, we can't rely on the `.git`
-        // directory being present, so look instead for the presence of a
-        // CHANGELOG.md file
-        let repo_root = cwd
-            .ancestors()
-            .find(|path| path.join("CHANGELOG.md").exists())
-            .unwrap_or_else(|| {
-                panic!(
-                    "Couldn't find the root of the repository for the current \
-                     working directory {}",
-                    cwd.to_string_lossy()
-                )
-            });
-        repo_root.join(WASM_FOR_TESTS_DIR).join(filename)
+        }
+    }
+
+    /// Get the expected SHA-256 checksum of this test wasm from the checked-in
+    /// manifest, if any.
+    pub fn expected_sha256(&self) -> Option<Hash> {
+        let manifest = std::fs::read_to_string(
+            wasm_for_tests_dir().join(WASM_CHECKSUMS_FILE),
+        )
+        .ok()?;
+        let checksums: BTreeMap<String, String> =
+            serde_json::from_str(&manifest).ok()?;
+        checksums.get(self.filename())?.parse().ok()
     }
 
     /// Attempts to read the contents of this test wasm. Panics if it is not
@@ -102,6 +120,69 @@ impl TestWasms {
             )
         })
     }
+
+    /// Attempts to read the contents of this test wasm and checks them against
+    /// the expected checksum from the manifest. Panics if it is not able to
+    /// read the wasm.
+    pub fn verified_bytes(&self) -> Result<Vec<u8>, ChecksumError> {
+        let bytes = self.read_bytes();
+        verify_checksum(&bytes, self.expected_sha256())?;
+        Ok(bytes)
+    }
+}
+
+/// Check that the SHA-256 checksum of the given bytes matches the expected one.
+fn verify_checksum(
+    bytes: &[u8],
+    expected: Option<Hash>,
+) -> Result<(), ChecksumError> {
+    let expected = expected.ok_or(ChecksumError::Missing)?;
+    let actual = Hash::sha256(bytes);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ChecksumError::Mismatch { expected, actual })
+    }
+}
+
+/// Get the path to the directory with the test wasms, or panic if not able to.
+fn wasm_for_tests_dir() -> PathBuf {
+    let cwd =
+        env::current_dir().expect("Couldn't get current working directory");
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    find_wasm_for_tests_dir(&cwd, manifest_dir.as_deref()).unwrap_or_else(
+        || {
+            panic!(
+                "Couldn't find the root of the repository for the current \
+                 working directory {}, nor a {WASM_FOR_TESTS_DIR} directory \
+                 above the crate's manifest directory",
+                cwd.to_string_lossy()
+            )
+        },
+    )
+}
+
+/// Find the directory with the test wasms, starting the search from the given
+/// working directory and falling back to the crate's manifest directory.
+fn find_wasm_for_tests_dir(
+    cwd: &Path,
+    manifest_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    // crudely find the root of the repo, we can't rely on the `.git`
+    // directory being present, so look instead for the presence of a
+    // CHANGELOG.md file
+    if let Some(repo_root) = cwd
+        .ancestors()
+        .find(|path| path.join("CHANGELOG.md").exists())
+    {
+        return Some(repo_root.join(WASM_FOR_TESTS_DIR));
+    }
+    // outside of a checkout of the repo (e.g. when consumed as a published
+    // dependency), look for the test wasms above the crate being tested
+    manifest_dir?
+        .ancestors()
+        .map(|path| path.join(WASM_FOR_TESTS_DIR))
+        .find(|path| path.is_dir())
 }
 
 #[cfg(test)]
@@ -118,4 +199,55 @@ mod tests {
             assert!(path.exists());
         }
     }
+
+    #[test]
+    /// Tests that a tampered copy of a wasm fails verification.
+    fn test_verify_tampered_wasm() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(TestWasms::TxNoOp.filename());
+        let bytes = b"\0asm\x01\0\0\0".to_vec();
+        std::fs::write(&path, &bytes).unwrap();
+        let expected = Hash::sha256(&bytes);
+
+        let read = std::fs::read(&path).unwrap();
+        assert!(verify_checksum(&read, Some(expected)).is_ok());
+        assert!(matches!(
+            verify_checksum(&read, None),
+            Err(ChecksumError::Missing)
+        ));
+
+        // Tamper with the copied wasm
+        let mut tampered = bytes;
+        tampered.push(0);
+        std::fs::write(&path, &tampered).unwrap();
+
+        let read = std::fs::read(&path).unwrap();
+        assert!(matches!(
+            verify_checksum(&read, Some(expected)),
+            Err(ChecksumError::Mismatch { .. })
+        ));
+    }
+
+    #[test]
+    /// Tests that the test wasms are found from the manifest directory
+    /// outside of a checkout of the repo.
+    fn test_find_wasm_for_tests_dir_fallback() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project");
+        let manifest_dir = project.join("crate");
+        let cwd = dir.path().join("elsewhere");
+        std::fs::create_dir_all(&manifest_dir).unwrap();
+        std::fs::create_dir_all(&cwd).unwrap();
+
+        // Nothing to be found yet
+        assert_eq!(find_wasm_for_tests_dir(&cwd, Some(&manifest_dir)), None);
+        assert_eq!(find_wasm_for_tests_dir(&cwd, None), None);
+
+        let wasms_dir = project.join(WASM_FOR_TESTS_DIR);
+        std::fs::create_dir(&wasms_dir).unwrap();
+        assert_eq!(
+            find_wasm_for_tests_dir(&cwd, Some(&manifest_dir)),
+            Some(wasms_dir)
+        );
+    }
 }
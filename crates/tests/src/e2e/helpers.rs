@@ -35,7 +35,7 @@ use super::setup::{
     Test, TestDir, run_cosmos_cmd, sleep,
 };
 use crate::e2e::setup::{APPS_PACKAGE, Bin, CosmosChainType, Who, constants};
-use crate::strings::{LEDGER_STARTED, TX_APPLIED_SUCCESS};
+use crate::strings::{LEDGER_STARTED, TX_APPLIED_SUCCESS, TX_REJECTED};
 use crate::{run, run_as};
 
 /// Instantiate a new [`HttpClient`] to perform RPC requests with.
@@ -104,6 +104,44 @@ pub fn init_established_account(
     Ok(())
 }
 
+/// Assert that the client's transaction was successfully applied and that
+/// the client exited successfully.
+pub fn assert_tx_valid(client: &mut NamadaCmd) -> Result<()> {
+    client.exp_string(TX_APPLIED_SUCCESS).map_err(|e| {
+        eyre!("Expected the transaction to be applied, but got: {}", e)
+    })?;
+    client.assert_success();
+    Ok(())
+}
+
+/// Assert that the client's transaction was rejected with the VP of the
+/// given address among the rejecting VPs and that the client exited
+/// successfully.
+pub fn assert_tx_rejected_by(
+    client: &mut NamadaCmd,
+    addr: &Address,
+) -> Result<()> {
+    let (_, matched) = client
+        .exp_regex(&format!(r"{TX_REJECTED}: \[[^\]]*\]"))
+        .map_err(|e| {
+            eyre!(
+                "Expected the transaction to be rejected by {}, but got: {}",
+                addr,
+                e
+            )
+        })?;
+    if !matched.contains(&format!("\"{addr}\"")) {
+        return Err(eyre!(
+            "Expected the transaction to be rejected by {}, but the rejecting \
+             VPs were: {}",
+            addr,
+            matched
+        ));
+    }
+    client.assert_success();
+    Ok(())
+}
+
 /// Find the address of an account by its alias from the wallet
 pub fn find_address(test: &Test, alias: impl AsRef<str>) -> Result<Address> {
     let mut find = run!(
@@ -47,8 +47,9 @@ use super::helpers::{
 };
 use super::setup::{NamadaCmd, set_ethereum_bridge_mode, working_dir};
 use crate::e2e::helpers::{
-    epoch_sleep, find_address, find_bonded_stake, get_actor_rpc, get_epoch,
-    is_debug_mode, parse_reached_epoch,
+    assert_tx_rejected_by, assert_tx_valid, epoch_sleep, find_address,
+    find_bonded_stake, get_actor_rpc, get_epoch, is_debug_mode,
+    parse_reached_epoch,
 };
 use crate::e2e::setup::{
     self, Bin, Who, allow_duplicate_ips, apply_use_device, default_port_offset,
@@ -1797,6 +1798,7 @@ fn test_invalid_validator_txs() -> Result<()> {
 
     let validator_0_rpc = get_actor_rpc(&test, Who::Validator(0));
     let validator_1_rpc = get_actor_rpc(&test, Who::Validator(1));
+    let validator_1 = find_address(&test, "validator-1")?;
 
     // Try to change validator-1 commission rate as validator-0
     let tx_args = vec![
@@ -1812,8 +1814,7 @@ fn test_invalid_validator_txs() -> Result<()> {
     ];
     let mut client =
         run_as!(test, Who::Validator(0), Bin::Client, tx_args, Some(40))?;
-    client.exp_string(TX_REJECTED)?;
-    client.assert_success();
+    assert_tx_rejected_by(&mut client, &validator_1)?;
 
     // Try to deactivate validator-1 as validator-0
     let tx_args = vec![
@@ -1827,8 +1828,7 @@ fn test_invalid_validator_txs() -> Result<()> {
     ];
     let mut client =
         run_as!(test, Who::Validator(0), Bin::Client, tx_args, Some(40))?;
-    client.exp_string(TX_REJECTED)?;
-    client.assert_success();
+    assert_tx_rejected_by(&mut client, &validator_1)?;
 
     // Try to change the validator-1 website as validator-0
     let tx_args = vec![
@@ -1844,8 +1844,7 @@ fn test_invalid_validator_txs() -> Result<()> {
     ];
     let mut client =
         run_as!(test, Who::Validator(0), Bin::Client, tx_args, Some(40))?;
-    client.exp_string(TX_REJECTED)?;
-    client.assert_success();
+    assert_tx_rejected_by(&mut client, &validator_1)?;
 
     // Deactivate validator-1
     let tx_args = vec![
@@ -1859,8 +1858,7 @@ fn test_invalid_validator_txs() -> Result<()> {
     ];
     let mut client =
         run_as!(test, Who::Validator(1), Bin::Client, tx_args, Some(40))?;
-    client.exp_string(TX_APPLIED_SUCCESS)?;
-    client.assert_success();
+    assert_tx_valid(&mut client)?;
 
     let deactivate_epoch = get_epoch(&test, &validator_1_rpc)?;
     #[allow(clippy::disallowed_methods)]
@@ -1909,8 +1907,7 @@ fn test_invalid_validator_txs() -> Result<()> {
     ];
     let mut client =
         run_as!(test, Who::Validator(0), Bin::Client, tx_args, Some(40))?;
-    client.exp_string(TX_REJECTED)?;
-    client.assert_success();
+    assert_tx_rejected_by(&mut client, &validator_1)?;
 
     Ok(())
 }
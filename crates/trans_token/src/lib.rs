@@ -23,7 +23,6 @@ pub mod storage_key;
 pub mod tx;
 pub mod vp;
 
-use std::collections::BTreeMap;
 use std::marker::PhantomData;
 
 use event::{TokenEvent, TokenOperation};
@@ -192,44 +191,11 @@ where
         source: UserAccount,
         target: UserAccount,
     ) -> Result<()> {
-        let post_source_balance: Option<Uint> = match &source {
-            UserAccount::Internal(addr) => {
-                Some(Self::read_balance(storage, token, addr)?.into())
-            }
-            UserAccount::External(_) => None,
-        };
-        let post_target_balance: Option<Uint> = match &target {
-            UserAccount::Internal(addr) => {
-                Some(Self::read_balance(storage, token, addr)?.into())
-            }
-            UserAccount::External(_) => None,
-        };
-
-        let sources = BTreeMap::from_iter([(
-            (source.clone(), token.clone()),
-            amount.into(),
-        )]);
-        let targets = BTreeMap::from_iter([(
-            (target.clone(), token.clone()),
-            amount.into(),
-        )]);
-
-        let mut post_balances = BTreeMap::new();
-        if let Some(balance) = post_source_balance {
-            post_balances.insert((source, token.clone()), balance);
+        if let Some(event) = storage::transfer_event(
+            storage, descriptor, level, token, amount, source, target,
+        )? {
+            storage.emit(event);
         }
-        if let Some(balance) = post_target_balance {
-            post_balances.insert((target, token.clone()), balance);
-        }
-        storage.emit(TokenEvent {
-            descriptor,
-            level,
-            operation: TokenOperation::Transfer {
-                sources,
-                targets,
-                post_balances,
-            },
-        });
         Ok(())
     }
 }
@@ -1,10 +1,16 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
 use namada_core::address::{Address, InternalAddress};
 use namada_core::hints;
 pub use namada_core::storage::Key;
 use namada_core::token::{self, Amount, AmountError, DenominatedAmount};
+use namada_core::uint::Uint;
+use namada_events::EventLevel;
 
+use crate::event::{TokenEvent, TokenOperation};
 use crate::storage_key::*;
-use crate::{Error, Result, ResultExt, StorageRead, StorageWrite};
+use crate::{Error, Result, ResultExt, StorageRead, StorageWrite, UserAccount};
 
 /// Initialize parameters for the token in storage during the genesis block.
 pub fn write_params<S>(storage: &mut S, address: &Address) -> Result<()>
@@ -29,6 +35,26 @@ where
     Ok(balance)
 }
 
+/// Read the non-zero balances of a given owner in the given tokens, keyed by
+/// the token address.
+pub fn read_balances<'a, S>(
+    storage: &S,
+    owner: &Address,
+    tokens: impl IntoIterator<Item = &'a Address>,
+) -> Result<BTreeMap<Address, token::Amount>>
+where
+    S: StorageRead,
+{
+    let mut result = BTreeMap::new();
+    for token in tokens {
+        let amount = read_balance(storage, token, owner)?;
+        if !amount.is_zero() {
+            result.insert(token.clone(), amount);
+        }
+    }
+    Ok(result)
+}
+
 /// Update the balance of a given token and owner.
 pub fn update_balance<S, F>(
     storage: &mut S,
@@ -216,6 +242,29 @@ where
     storage.write(&key, denom)
 }
 
+/// Read the metadata of a given token, if any.
+pub fn read_metadata<S>(
+    storage: &S,
+    token: &Address,
+) -> Result<Option<token::TokenMetadata>>
+where
+    S: StorageRead,
+{
+    storage.read(&metadata_key(token))
+}
+
+/// Write the metadata of a given token.
+pub fn write_metadata<S>(
+    storage: &mut S,
+    token: &Address,
+    metadata: &token::TokenMetadata,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    storage.write(&metadata_key(token), metadata)
+}
+
 /// Apply transfer of a `token` from `src` to `dest` in storage.
 ///
 /// Returns an `Err` if `src` has insufficient balance or if the transfer the
@@ -261,6 +310,48 @@ where
     }
 }
 
+/// Build the event of a transfer of `amount` of `token` from `source` to
+/// `target`, including the post balances of the internal accounts, which must
+/// already have been updated. Returns `None` for a zero amount transfer.
+pub fn transfer_event<S>(
+    storage: &S,
+    descriptor: Cow<'static, str>,
+    level: EventLevel,
+    token: &Address,
+    amount: token::Amount,
+    source: UserAccount,
+    target: UserAccount,
+) -> Result<Option<TokenEvent>>
+where
+    S: StorageRead,
+{
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    let mut post_balances = BTreeMap::new();
+    for account in [&source, &target] {
+        if let UserAccount::Internal(addr) = account {
+            let balance: Uint = read_balance(storage, token, addr)?.into();
+            post_balances.insert((account.clone(), token.clone()), balance);
+        }
+    }
+    let sources =
+        BTreeMap::from_iter([((source, token.clone()), amount.into())]);
+    let targets =
+        BTreeMap::from_iter([((target, token.clone()), amount.into())]);
+
+    Ok(Some(TokenEvent {
+        descriptor,
+        level,
+        operation: TokenOperation::Transfer {
+            sources,
+            targets,
+            post_balances,
+        },
+    }))
+}
+
 /// Mint `amount` of `token` as `minter` to `dest`.
 pub fn mint_tokens<S>(
     storage: &mut S,
@@ -365,11 +456,18 @@ pub fn denom_to_amount(
 
 #[cfg(test)]
 mod testing {
+    use namada_core::uint::Uint;
     use namada_core::{address, token};
+    use namada_events::{Event, EventLevel};
     use namada_state::testing::TestStorage;
 
     use super::{
         burn_tokens, credit_tokens, read_balance, read_total_supply, transfer,
+        transfer_event,
+    };
+    use crate::UserAccount;
+    use crate::event::{
+        PostBalances, SourceAccounts, TargetAccounts, types as event_types,
     };
 
     #[test]
@@ -425,6 +523,68 @@ mod testing {
         assert_eq!(post_balance, pre_balance);
     }
 
+    #[test]
+    fn test_transfer_event() {
+        let mut storage = TestStorage::default();
+        let native_token = address::testing::nam();
+        let src = address::testing::established_address_1();
+        let dest = address::testing::established_address_2();
+
+        let amount = token::Amount::native_whole(1);
+        credit_tokens(&mut storage, &native_token, &src, amount * 3).unwrap();
+        transfer(&mut storage, &native_token, &src, &dest, amount).unwrap();
+
+        let event: Event = transfer_event(
+            &storage,
+            "transfer-from-test".into(),
+            EventLevel::Tx,
+            &native_token,
+            amount,
+            UserAccount::Internal(src.clone()),
+            UserAccount::Internal(dest.clone()),
+        )
+        .unwrap()
+        .unwrap()
+        .into();
+        assert_eq!(*event.kind(), event_types::TRANSFER);
+
+        let src_account = (UserAccount::Internal(src), native_token.clone());
+        let dest_account = (UserAccount::Internal(dest), native_token.clone());
+        let amount = Uint::from(amount);
+        assert_eq!(
+            event.read_attribute::<SourceAccounts>().unwrap().0,
+            vec![(src_account.clone(), amount)]
+        );
+        assert_eq!(
+            event.read_attribute::<TargetAccounts>().unwrap().0,
+            vec![(dest_account.clone(), amount)]
+        );
+        let mut post_balances =
+            event.read_attribute::<PostBalances>().unwrap().0;
+        post_balances.sort();
+        let mut expected = vec![
+            (src_account, Uint::from(token::Amount::native_whole(2))),
+            (dest_account, amount),
+        ];
+        expected.sort();
+        assert_eq!(post_balances, expected);
+
+        // No event for a zero amount transfer
+        assert!(
+            transfer_event(
+                &storage,
+                "transfer-from-test".into(),
+                EventLevel::Tx,
+                &native_token,
+                token::Amount::zero(),
+                UserAccount::Internal(address::testing::established_address_1()),
+                UserAccount::Internal(address::testing::established_address_2()),
+            )
+            .unwrap()
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_transfer() {
         let mut storage = TestStorage::default();
@@ -7,6 +7,8 @@ use namada_core::storage::{self, DbKeySeg, KeySeg};
 pub const BALANCE_STORAGE_KEY: &str = "balance";
 /// Key segment for a denomination key
 pub const DENOM_STORAGE_KEY: &str = "denomination";
+/// Key segment for a token metadata key
+pub const METADATA_STORAGE_KEY: &str = "metadata";
 /// Key segment for multitoken minter
 pub const MINTER_STORAGE_KEY: &str = "minter";
 /// Key segment for minted balance
@@ -140,6 +142,27 @@ pub fn is_any_token_balance_key(key: &storage::Key) -> Option<[&Address; 2]> {
     }
 }
 
+/// Split a balance key in the storage subspace of the given `vp` account back
+/// into its token and owner addresses. Returns `None` if the key is not a
+/// balance key under `vp`. The balances built by [`balance_key()`] live under
+/// the [`InternalAddress::Multitoken`] account.
+pub fn parse_balance_key(
+    key: &storage::Key,
+    vp: &Address,
+) -> Option<(Address, Address)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(balance),
+            DbKeySeg::AddressSeg(owner),
+        ] if addr == vp && balance == BALANCE_STORAGE_KEY => {
+            Some((token.clone(), owner.clone()))
+        }
+        _ => None,
+    }
+}
+
 /// Obtain a storage key denomination of a token.
 pub fn denom_key(token_addr: &Address) -> storage::Key {
     storage::Key::from(token_addr.to_db_key())
@@ -147,6 +170,13 @@ pub fn denom_key(token_addr: &Address) -> storage::Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Obtain a storage key for the metadata of a token.
+pub fn metadata_key(token_addr: &Address) -> storage::Key {
+    storage::Key::from(token_addr.to_db_key())
+        .push(&METADATA_STORAGE_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Check if the given storage key is a denomination key for the given token.
 pub fn is_denom_key(token_addr: &Address, key: &storage::Key) -> bool {
     matches!(&key.segments[..],
@@ -192,3 +222,88 @@ pub fn is_any_minted_balance_key(key: &storage::Key) -> Option<&Address> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::{established_address_1, nam};
+
+    use super::*;
+
+    #[test]
+    fn test_balance_key_roundtrip() {
+        let token = nam();
+        let owner = established_address_1();
+        let key = balance_key(&token, &owner);
+
+        assert_eq!(is_balance_key(&token, &key), Some(&owner));
+        assert_eq!(is_any_token_balance_key(&key), Some([&token, &owner]));
+
+        // The key must survive a round-trip through its string encoding
+        let parsed = storage::Key::parse(key.to_string()).unwrap();
+        assert_eq!(parsed, key);
+        assert_eq!(is_any_token_balance_key(&parsed), Some([&token, &owner]));
+    }
+
+    #[test]
+    fn test_non_balance_keys() {
+        let token = nam();
+        let owner = established_address_1();
+
+        // A balance key of another token
+        let key = balance_key(&owner, &owner);
+        assert_eq!(is_balance_key(&token, &key), None);
+
+        // The minted balance key shares the balance prefix
+        let key = minted_balance_key(&token);
+        assert_eq!(is_balance_key(&token, &key), None);
+        assert_eq!(is_any_token_balance_key(&key), None);
+
+        // Keys that are not under the multitoken address
+        let key = storage::Key::from(token.to_db_key())
+            .push(&BALANCE_STORAGE_KEY.to_owned())
+            .unwrap()
+            .push(&owner.to_db_key())
+            .unwrap();
+        assert_eq!(is_any_token_balance_key(&key), None);
+        assert_eq!(is_any_token_balance_key(&minter_key(&token)), None);
+    }
+
+    #[test]
+    fn test_parse_balance_key() {
+        let token = nam();
+        let owner = established_address_1();
+        let multitoken = Address::Internal(InternalAddress::Multitoken);
+        let key = balance_key(&token, &owner);
+        assert_eq!(
+            parse_balance_key(&key, &multitoken),
+            Some((token.clone(), owner.clone()))
+        );
+
+        // The same key isn't a balance key under another account
+        assert_eq!(parse_balance_key(&key, &token), None);
+
+        // Non-balance keys under the multitoken account
+        assert_eq!(
+            parse_balance_key(&minted_balance_key(&token), &multitoken),
+            None
+        );
+        assert_eq!(
+            parse_balance_key(&balance_prefix(&token), &multitoken),
+            None
+        );
+    }
+
+    #[test]
+    fn test_balance_key_account_prefix() {
+        let token = nam();
+        let owner = established_address_1();
+        let key = balance_key(&token, &owner);
+
+        // Balances live in the subspace of the multitoken account, rather
+        // than in the owner's one
+        let multitoken = Address::Internal(InternalAddress::Multitoken);
+        assert!(key.split_prefix(&multitoken.to_db_prefix()).is_some());
+        assert!(key.split_prefix(&owner.to_db_prefix()).is_none());
+        assert_eq!(key.first(), multitoken.to_db_prefix().first(),);
+    }
+}
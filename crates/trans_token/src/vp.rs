@@ -28,6 +28,118 @@ enum Owner<'a> {
     Protocol,
 }
 
+/// The balance and minted supply changes of the tokens touched by a tx.
+#[derive(Debug, Default)]
+pub struct TokenChanges {
+    inc_changes: HashMap<Address, Amount>,
+    dec_changes: HashMap<Address, Amount>,
+    inc_mints: HashMap<Address, Amount>,
+    dec_mints: HashMap<Address, Amount>,
+}
+
+impl TokenChanges {
+    /// Record a change of a balance of the given token
+    pub fn record_balance_change(
+        &mut self,
+        token: &Address,
+        pre: Amount,
+        post: Amount,
+    ) -> Result<()> {
+        record_change(
+            &mut self.inc_changes,
+            &mut self.dec_changes,
+            token,
+            pre,
+            post,
+        )
+    }
+
+    /// Record a change of the minted supply of the given token
+    pub fn record_minted_change(
+        &mut self,
+        token: &Address,
+        pre: Amount,
+        post: Amount,
+    ) -> Result<()> {
+        record_change(
+            &mut self.inc_mints,
+            &mut self.dec_mints,
+            token,
+            pre,
+            post,
+        )
+    }
+
+    /// The tokens with any recorded changes
+    pub fn tokens(&self) -> BTreeSet<&Address> {
+        self.inc_changes
+            .keys()
+            .chain(self.dec_changes.keys())
+            .chain(self.inc_mints.keys())
+            .chain(self.dec_mints.keys())
+            .collect()
+    }
+
+    /// Check that the balance increases and decreases of each token net to
+    /// the change of its minted supply, i.e. that tokens are only created or
+    /// destroyed by a mint or a burn.
+    pub fn validate_conservation(&self) -> Result<()> {
+        self.tokens().into_iter().try_for_each(|token| {
+            let inc_change =
+                self.inc_changes.get(token).cloned().unwrap_or_default();
+            let dec_change =
+                self.dec_changes.get(token).cloned().unwrap_or_default();
+            let inc_mint =
+                self.inc_mints.get(token).cloned().unwrap_or_default();
+            let dec_mint =
+                self.dec_mints.get(token).cloned().unwrap_or_default();
+
+            let token_changes_are_balanced =
+                if inc_change >= dec_change && inc_mint >= dec_mint {
+                    inc_change.checked_sub(dec_change)
+                        == inc_mint.checked_sub(dec_mint)
+                } else if (inc_change < dec_change && inc_mint >= dec_mint)
+                    || (inc_change >= dec_change && inc_mint < dec_mint)
+                {
+                    false
+                } else {
+                    dec_change.checked_sub(inc_change)
+                        == dec_mint.checked_sub(inc_mint)
+                };
+
+            token_changes_are_balanced.ok_or_else(|| {
+                Error::new_const(
+                    "The transaction's token changes are unbalanced",
+                )
+            })
+        })
+    }
+}
+
+/// Add the difference between `pre` and `post` to the increases or the
+/// decreases of the given token
+fn record_change(
+    increases: &mut HashMap<Address, Amount>,
+    decreases: &mut HashMap<Address, Amount>,
+    token: &Address,
+    pre: Amount,
+    post: Amount,
+) -> Result<()> {
+    let (change, diff) = match post.checked_sub(pre) {
+        Some(diff) => (increases.entry(token.clone()).or_default(), diff),
+        None => {
+            let diff = pre
+                .checked_sub(post)
+                .expect("Underflow shouldn't happen here");
+            (decreases.entry(token.clone()).or_default(), diff)
+        }
+    };
+    *change = change
+        .checked_add(diff)
+        .ok_or_else(|| Error::new_const("Overflowed in balance check"))?;
+    Ok(())
+}
+
 /// Multitoken VP
 pub struct MultitokenVp<'ctx, CTX, Params, Gov> {
     /// Generic types for DI
@@ -92,10 +204,7 @@ where
                     }))
         };
 
-        let mut inc_changes: HashMap<Address, Amount> = HashMap::new();
-        let mut dec_changes: HashMap<Address, Amount> = HashMap::new();
-        let mut inc_mints: HashMap<Address, Amount> = HashMap::new();
-        let mut dec_mints: HashMap<Address, Amount> = HashMap::new();
+        let mut changes = TokenChanges::default();
         for key in keys_changed {
             if let Some([token, owner]) = is_any_token_balance_key(key) {
                 if !verifiers.contains(owner) {
@@ -107,43 +216,20 @@ where
 
                 let pre: Amount = ctx.read_pre(key)?.unwrap_or_default();
                 let post: Amount = ctx.read_post(key)?.unwrap_or_default();
-                match post.checked_sub(pre) {
-                    Some(diff) => {
-                        if !is_allowed_inc(token, owner) {
-                            tracing::debug!(
-                                "Native token deposit isn't allowed"
-                            );
-                            return Err(Error::new_const(
-                                "Native token deposit isn't allowed",
-                            ));
-                        }
-                        let change =
-                            inc_changes.entry(token.clone()).or_default();
-                        *change =
-                            change.checked_add(diff).ok_or_else(|| {
-                                Error::new_const("Overflowed in balance check")
-                            })?;
-                    }
-                    None => {
-                        if !is_allowed_dec(token, owner) {
-                            tracing::debug!(
-                                "Native token withdraw isn't allowed"
-                            );
-                            return Err(Error::new_const(
-                                "Native token deposit isn't allowed",
-                            ));
-                        }
-                        let diff = pre
-                            .checked_sub(post)
-                            .expect("Underflow shouldn't happen here");
-                        let change =
-                            dec_changes.entry(token.clone()).or_default();
-                        *change =
-                            change.checked_add(diff).ok_or_else(|| {
-                                Error::new_const("Overflowed in balance check")
-                            })?;
+                if post >= pre {
+                    if !is_allowed_inc(token, owner) {
+                        tracing::debug!("Native token deposit isn't allowed");
+                        return Err(Error::new_const(
+                            "Native token deposit isn't allowed",
+                        ));
                     }
+                } else if !is_allowed_dec(token, owner) {
+                    tracing::debug!("Native token withdraw isn't allowed");
+                    return Err(Error::new_const(
+                        "Native token deposit isn't allowed",
+                    ));
                 }
+                changes.record_balance_change(token, pre, post)?;
             } else if let Some(token) = is_any_minted_balance_key(key) {
                 if *token == native_token && !is_native_token_transferable {
                     tracing::debug!(
@@ -156,23 +242,7 @@ where
 
                 let pre: Amount = ctx.read_pre(key)?.unwrap_or_default();
                 let post: Amount = ctx.read_post(key)?.unwrap_or_default();
-                match post.checked_sub(pre) {
-                    Some(diff) => {
-                        let mint = inc_mints.entry(token.clone()).or_default();
-                        *mint = mint.checked_add(diff).ok_or_else(|| {
-                            Error::new_const("Overflowed in balance check")
-                        })?;
-                    }
-                    None => {
-                        let diff = pre
-                            .checked_sub(post)
-                            .expect("Underflow shouldn't happen here");
-                        let mint = dec_mints.entry(token.clone()).or_default();
-                        *mint = mint.checked_add(diff).ok_or_else(|| {
-                            Error::new_const("Overflowed in balance check")
-                        })?;
-                    }
-                }
+                changes.record_minted_change(token, pre, post)?;
                 // Check if the minter is set
                 Self::is_valid_minter(ctx, token, verifiers)?;
             } else if let Some(token) = is_any_minter_key(key) {
@@ -192,13 +262,7 @@ where
             }
         }
 
-        let mut all_tokens = BTreeSet::new();
-        all_tokens.extend(inc_changes.keys().cloned());
-        all_tokens.extend(dec_changes.keys().cloned());
-        all_tokens.extend(inc_mints.keys().cloned());
-        all_tokens.extend(dec_mints.keys().cloned());
-
-        all_tokens.iter().try_for_each(|token| {
+        changes.tokens().into_iter().try_for_each(|token| {
             if token.is_internal()
                 && matches!(token, Address::Internal(InternalAddress::Nut(_)))
                 && !verifiers.contains(token)
@@ -211,33 +275,10 @@ where
                     "Token {token} must verify the tx"
                 )));
             }
+            Ok(())
+        })?;
 
-            let inc_change =
-                inc_changes.get(token).cloned().unwrap_or_default();
-            let dec_change =
-                dec_changes.get(token).cloned().unwrap_or_default();
-            let inc_mint = inc_mints.get(token).cloned().unwrap_or_default();
-            let dec_mint = dec_mints.get(token).cloned().unwrap_or_default();
-
-            let token_changes_are_balanced =
-                if inc_change >= dec_change && inc_mint >= dec_mint {
-                    inc_change.checked_sub(dec_change)
-                        == inc_mint.checked_sub(dec_mint)
-                } else if (inc_change < dec_change && inc_mint >= dec_mint)
-                    || (inc_change >= dec_change && inc_mint < dec_mint)
-                {
-                    false
-                } else {
-                    dec_change.checked_sub(inc_change)
-                        == dec_mint.checked_sub(inc_mint)
-                };
-
-            token_changes_are_balanced.ok_or_else(|| {
-                Error::new_const(
-                    "The transaction's token changes are unbalanced",
-                )
-            })
-        })
+        changes.validate_conservation()
     }
 
     /// Return the minter if the minter is valid and the minter VP exists
@@ -440,6 +481,50 @@ mod tests {
         keys_changed
     }
 
+    #[test]
+    fn test_validate_conservation() {
+        let token = nam();
+        let other_token = established_address_1();
+
+        // A balanced transfer
+        let mut changes = TokenChanges::default();
+        changes
+            .record_balance_change(&token, Amount::from(100), Amount::from(90))
+            .unwrap();
+        changes
+            .record_balance_change(&token, Amount::zero(), Amount::from(10))
+            .unwrap();
+        changes.validate_conservation().unwrap();
+
+        // An unbalanced transfer of another token
+        changes
+            .record_balance_change(
+                &other_token,
+                Amount::from(100),
+                Amount::from(90),
+            )
+            .unwrap();
+        changes
+            .record_balance_change(
+                &other_token,
+                Amount::zero(),
+                Amount::from(11),
+            )
+            .unwrap();
+        assert!(changes.validate_conservation().is_err());
+
+        // A mint accounted for by the minted supply
+        let mut changes = TokenChanges::default();
+        changes
+            .record_balance_change(&token, Amount::zero(), Amount::from(10))
+            .unwrap();
+        assert!(changes.validate_conservation().is_err());
+        changes
+            .record_minted_change(&token, Amount::from(5), Amount::from(15))
+            .unwrap();
+        changes.validate_conservation().unwrap();
+    }
+
     #[test]
     fn test_valid_transfer() {
         let mut state = init_state();
@@ -11,7 +11,7 @@ pub mod protocol;
 pub mod wrapper;
 
 use std::borrow::Cow;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Display};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
@@ -379,6 +379,24 @@ impl<T> TxResult<T> {
     pub fn are_any_err(&self) -> bool {
         self.iter().any(|(_, res)| res.is_err())
     }
+
+    /// Collect the addresses of the VPs triggered by any of the applied inner
+    /// txs, sorted by the address, together with whether the VP accepted all
+    /// of the inner txs that triggered it.
+    pub fn triggered_vps(&self) -> Vec<(Address, bool)> {
+        let mut vps = BTreeMap::new();
+        for batched_result in self.values().filter_map(|res| res.as_ref().ok())
+        {
+            let vps_result = &batched_result.vps_result;
+            for addr in &vps_result.accepted_vps {
+                vps.entry(addr.clone()).or_insert(true);
+            }
+            for addr in &vps_result.rejected_vps {
+                vps.insert(addr.clone(), false);
+            }
+        }
+        vps.into_iter().collect()
+    }
 }
 
 #[cfg(feature = "migrations")]
@@ -476,6 +494,10 @@ pub struct VpsResult {
     /// about conditions that caused their evaluation to
     /// fail.
     pub status_flags: VpStatusFlags,
+    /// Events emitted by the VPs that accepted the transaction
+    #[serde(skip_serializing, skip_deserializing)]
+    #[borsh(skip)]
+    pub events: BTreeSet<Event>,
 }
 
 impl<T: Serialize> fmt::Display for TxResult<T> {
@@ -781,6 +803,7 @@ mod test_process_tx {
                 rejected_vps: [POS].into(),
                 errors: vec![(POS, "Pos error".to_string())],
                 status_flags: VpStatusFlags::empty(),
+                events: BTreeSet::new(),
             },
             initialized_accounts: vec![
                 established_address_1(),
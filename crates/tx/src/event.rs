@@ -3,11 +3,12 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::ibc::IbcTxDataHash;
 use namada_core::masp::MaspTxId;
 use namada_events::extend::{
-    ComposeEvent, EventAttributeEntry, Height, Log, TxHash,
+    ComposeEvent, EventAttributeEntry, EventValue, Height, Log, TxHash,
 };
 use namada_events::{Event, EventLevel, EventToEmit, EventType};
 use namada_macros::BorshDeserializer;
@@ -98,6 +99,21 @@ impl<'result> EventAttributeEntry<'result> for Batch<'result> {
     }
 }
 
+/// Extend an [`Event`] with the VPs triggered by a transaction, sorted by
+/// their address, and whether each of them accepted it.
+pub struct TriggeredVps(pub EventValue<Vec<(Address, bool)>>);
+
+impl EventAttributeEntry<'static> for TriggeredVps {
+    type Value = EventValue<Vec<(Address, bool)>>;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "triggered-vps";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
 pub mod masp_types {
     //! MASP event types
 
@@ -225,3 +241,62 @@ impl EventAttributeEntry<'static> for IndexedTx {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use either::Either;
+    use namada_core::address::MULTITOKEN;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_core::hash::Hash;
+
+    use super::*;
+    use crate::data::{BatchedTxResult, VpsResult};
+
+    #[test]
+    fn test_triggered_vps_event() {
+        let source = established_address_1();
+        let target = established_address_2();
+
+        // A transfer accepted by the token and both the accounts' VPs
+        let transfer = BatchedTxResult {
+            vps_result: VpsResult {
+                accepted_vps: [MULTITOKEN, source.clone(), target.clone()]
+                    .into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // A second transfer rejected by the target
+        let rejected_transfer = BatchedTxResult {
+            vps_result: VpsResult {
+                accepted_vps: [MULTITOKEN, source.clone()].into(),
+                rejected_vps: [target.clone()].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut tx_result = TxResult::<String>::new();
+        tx_result.insert_inner_tx_result(
+            None,
+            Either::Left(&Hash::sha256(b"transfer")),
+            Ok(transfer),
+        );
+        tx_result.insert_inner_tx_result(
+            None,
+            Either::Left(&Hash::sha256(b"rejected transfer")),
+            Ok(rejected_transfer),
+        );
+
+        let event: Event = Event::new(types::APPLIED, EventLevel::Tx)
+            .with(TriggeredVps(tx_result.triggered_vps().into()))
+            .into();
+        let triggered_vps = event.read_attribute::<TriggeredVps>().unwrap().0;
+
+        let mut expected =
+            vec![(MULTITOKEN, true), (source, true), (target, false)];
+        expected.sort();
+        assert_eq!(triggered_vps, expected);
+    }
+}
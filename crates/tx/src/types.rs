@@ -61,6 +61,16 @@ pub enum TxError {
     RepeatedSections,
 }
 
+/// Error of a transaction signed for a different chain
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Tx carries a wrong chain id: expected {expected}, found {found}")]
+pub struct ChainIdMismatch {
+    /// The chain id of the node
+    pub expected: ChainId,
+    /// The chain id carried by the transaction
+    pub found: ChainId,
+}
+
 /// A Namada transaction is represented as a header followed by a series of
 /// sections providing additional details.
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -208,6 +218,30 @@ impl Tx {
         self.header.clone()
     }
 
+    /// Check if the transaction has expired at the given time. Transactions
+    /// without an expiration never expire.
+    pub fn is_expired(&self, current_time: DateTimeUtc) -> bool {
+        self.header
+            .expiration
+            .is_some_and(|expiration| current_time > expiration)
+    }
+
+    /// Check that the transaction was made for the `expected` chain, to
+    /// prevent replays of transactions from other chains.
+    pub fn validate_chain_id(
+        &self,
+        expected: &ChainId,
+    ) -> std::result::Result<(), ChainIdMismatch> {
+        if &self.header.chain_id == expected {
+            Ok(())
+        } else {
+            Err(ChainIdMismatch {
+                expected: expected.clone(),
+                found: self.header.chain_id.clone(),
+            })
+        }
+    }
+
     /// Get the transaction's wrapper hash
     pub fn wrapper_hash(&self) -> Option<namada_core::hash::Hash> {
         matches!(&self.header.tx_type, TxType::Wrapper(_))
@@ -1064,6 +1098,51 @@ mod test {
     use crate::data;
     use crate::data::protocol::{ProtocolTx, ProtocolTxType};
 
+    /// Test the expiration check of a transaction
+    #[test]
+    fn test_tx_is_expired() {
+        let now = DateTimeUtc::from_unix_timestamp(1_000).unwrap();
+        let earlier = DateTimeUtc::from_unix_timestamp(999).unwrap();
+        let later = DateTimeUtc::from_unix_timestamp(1_001).unwrap();
+        let chain_id = ChainId::default();
+
+        let expired = Tx::new(chain_id.clone(), Some(earlier));
+        assert!(expired.is_expired(now));
+
+        let not_yet_expired = Tx::new(chain_id.clone(), Some(later));
+        assert!(!not_yet_expired.is_expired(now));
+        // A tx is still valid at its exact expiration time
+        assert!(!not_yet_expired.is_expired(later));
+
+        let never_expires = Tx::new(chain_id, None);
+        assert!(!never_expires.is_expired(now));
+    }
+
+    /// Test the chain id check of a transaction
+    #[test]
+    fn test_tx_validate_chain_id() {
+        let chain_id = ChainId("namada-test.0123456789abcdef".to_string());
+        let other_chain_id =
+            ChainId("namada-other.0123456789abcdef".to_string());
+        let tx = Tx::new(chain_id.clone(), None);
+
+        assert_eq!(tx.validate_chain_id(&chain_id), Ok(()));
+
+        let err = tx.validate_chain_id(&other_chain_id).unwrap_err();
+        assert_eq!(
+            err,
+            ChainIdMismatch {
+                expected: other_chain_id,
+                found: chain_id,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Tx carries a wrong chain id: expected \
+             namada-other.0123456789abcdef, found namada-test.0123456789abcdef"
+        );
+    }
+
     /// Test that the BorshSchema for Tx gets generated without any name
     /// conflicts
     #[test]
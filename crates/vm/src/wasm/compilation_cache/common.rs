@@ -496,6 +496,75 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
         }
     }
 
+    /// Compile the WASM files in the `wasm_dir` whose names start with the
+    /// given `file_prefix` and whose code hash is accepted by `is_allowed`,
+    /// and persist the compiled modules, so that the first txs using them
+    /// don't have to wait for the compilation. Unlike [`Cache::pre_compile`],
+    /// this blocks until all the modules are compiled. Files that cannot be
+    /// read or compiled are skipped. Returns the hashes of the compiled WASMs.
+    pub fn warm_up(
+        &mut self,
+        wasm_dir: impl AsRef<Path>,
+        file_prefix: &str,
+        is_allowed: impl Fn(&Hash) -> bool,
+    ) -> Vec<Hash> {
+        let wasm_dir = wasm_dir.as_ref();
+        let entries = match fs::read_dir(wasm_dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to read the wasm directory {}: {err}",
+                    wasm_dir.to_string_lossy()
+                );
+                return vec![];
+            }
+        };
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "wasm")
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(file_prefix))
+            })
+            .collect();
+        files.sort();
+
+        let mut hashes = Vec::with_capacity(files.len());
+        for file in files {
+            let code = match fs::read(&file) {
+                Ok(code) => code,
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to read WASM {}: {err}",
+                        file.to_string_lossy()
+                    );
+                    continue;
+                }
+            };
+            let code_hash = hash_of_code(&code);
+            if !is_allowed(&code_hash) {
+                continue;
+            }
+            match self.compile_or_fetch(&code) {
+                Ok(Some(_)) => hashes.push(code_hash),
+                Ok(None) => {}
+                Err(err) => tracing::warn!(
+                    "Failed to warm up {} with WASM {}: {err}",
+                    N::name(),
+                    file.to_string_lossy()
+                ),
+            }
+        }
+        tracing::info!(
+            "Warmed up {} with {} WASM modules.",
+            N::name(),
+            hashes.len()
+        );
+        hashes
+    }
+
     /// Get a read-only cache handle.
     pub fn read_only(&self) -> Cache<N, WasmCacheRoAccess> {
         Cache {
@@ -509,6 +578,8 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
     }
 }
 
+/// The content-addressed cache key of a wasm code. This is the same hash as the
+/// one used to refer to wasm codes in storage.
 fn hash_of_code(code: impl AsRef<[u8]>) -> Hash {
     Hash::sha256(code.as_ref())
 }
@@ -879,6 +950,16 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_hash_of_code_content_addressed() {
+        let code = vec![0_u8, 97, 115, 109, 1, 0, 0, 0];
+        assert_eq!(hash_of_code(&code), hash_of_code(code.clone()));
+
+        let mut changed_code = code.clone();
+        changed_code[7] = 1;
+        assert_ne!(hash_of_code(&code), hash_of_code(&changed_code));
+    }
+
     #[test]
     fn test_fetch_or_compile_invalid_wasm() {
         // Some random bytes
@@ -1066,6 +1147,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_warm_up() {
+        let wasm_dir = tempdir().unwrap();
+        // Minimal valid WASM modules, made distinct by a custom section
+        let wasms: Vec<Vec<u8>> = [b'a', b'b', b'c']
+            .into_iter()
+            .map(|name| vec![0_u8, 97, 115, 109, 1, 0, 0, 0, 0, 2, 1, name])
+            .collect();
+        for (i, code) in wasms.iter().enumerate() {
+            fs::write(wasm_dir.path().join(format!("vp_{i}.wasm")), code)
+                .unwrap();
+        }
+        // Files that don't match the prefix or aren't WASM are ignored
+        fs::write(wasm_dir.path().join("tx_0.wasm"), &wasms[0]).unwrap();
+        fs::write(wasm_dir.path().join("vp_checksums.json"), b"{}").unwrap();
+        // Invalid WASM is skipped
+        fs::write(wasm_dir.path().join("vp_invalid.wasm"), [1_u8, 0, 8])
+            .unwrap();
+
+        // The last WASM is not allowed
+        let disallowed = hash_of_code(&wasms[2]);
+
+        let (mut cache, _tmp_dir) = testing::cache::<TestCache>();
+        let hashes =
+            cache.warm_up(wasm_dir.path(), "vp_", |hash| hash != &disallowed);
+        let expected: Vec<Hash> = wasms[..2].iter().map(hash_of_code).collect();
+        assert_eq!(hashes, expected);
+
+        for hash in &expected {
+            let progress = cache.progress.read().unwrap();
+            assert_matches!(progress.get(hash), Some(Compilation::Done));
+            let in_memory = cache.in_memory.read().unwrap();
+            assert!(in_memory.peek(hash).is_some());
+            assert!(module_file_exists(&cache.dir, hash));
+        }
+        assert_eq!(cache.progress.read().unwrap().len(), expected.len());
+    }
+
     /// Get the WASM code bytes, its hash and find the compiled module's size
     fn load_wasm(file: impl AsRef<Path>) -> WasmWithMeta {
         let file = file.as_ref();
@@ -10,7 +10,8 @@ use std::marker::PhantomData;
 use namada_core::borsh;
 use namada_core::borsh::BorshDeserialize;
 use namada_core::chain::{ChainId, Epochs};
-use namada_gas::{Gas, GasMetering, VpGasMeter};
+use namada_events::EventToEmit;
+use namada_gas::{Gas, GasMetering, MEMORY_ACCESS_GAS_PER_BYTE, VpGasMeter};
 use namada_state::{ConversionState, ReadConversionState};
 use namada_tx::{BatchedTxRef, Tx, TxCommitments};
 
@@ -23,6 +24,10 @@ use crate::state::{
 pub use crate::state::{Error, Result, ResultExt};
 use crate::{Address, Event, EventType, Hash, VpEnv};
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod write_rate_limit;
+
 /// A native VP module should implement its validation logic using this trait.
 pub trait NativeVp<'a> {
     /// Run the validity predicate
@@ -66,6 +71,8 @@ where
     pub verifiers: &'a BTreeSet<Address>,
     /// VP WASM compilation cache
     pub vp_wasm_cache: CA,
+    /// Events emitted by the VP
+    pub events: RefCell<Vec<Event>>,
     /// VP evaluator type
     pub eval: PhantomData<EVAL>,
 }
@@ -139,10 +146,33 @@ where
             keys_changed,
             verifiers,
             vp_wasm_cache,
+            events: RefCell::new(Vec::new()),
             eval: PhantomData,
         }
     }
 
+    /// Emit an event from the VP. The events are buffered in the context and
+    /// they only get attached to the tx result if the tx gets committed.
+    pub fn emit_event<E: EventToEmit>(&self, event: E) -> Result<()> {
+        let event = event.into();
+        let gas = event
+            .emission_gas_cost(MEMORY_ACCESS_GAS_PER_BYTE)
+            .ok_or(Error::SimpleMessage("Overflow in gas"))?;
+        self.gas_meter
+            .borrow_mut()
+            .consume(gas.into())
+            .map_err(|_| {
+                Error::SimpleMessage("Gas limit exceeded in native vp")
+            })?;
+        self.events.borrow_mut().push(event);
+        Ok(())
+    }
+
+    /// Take the events emitted by the VP.
+    pub fn take_events(&self) -> Vec<Event> {
+        std::mem::take(&mut *self.events.borrow_mut())
+    }
+
     /// Read access to the prior storage (state before tx execution)
     /// via [`trait@StorageRead`].
     pub fn pre<'view>(
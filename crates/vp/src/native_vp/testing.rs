@@ -0,0 +1,149 @@
+//! Harness to unit-test native VPs in-process, without a full node.
+//!
+//! The harness applies the storage changes of a tx on top of a given pre-state
+//! and runs the native VP against them with an unlimited gas meter and a
+//! dummy raw tx.
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+use namada_gas::{TxGasMeter, VpGasMeter};
+use namada_tx::data::TxType;
+use namada_tx::{BatchedTxRef, Tx, TxCommitments};
+
+use super::{Ctx, VpEvaluator};
+use crate::state::testing::TestState;
+use crate::state::{Error, Key, Result, ResultExt, StateRead, TxIndex};
+use crate::{Address, Hash};
+
+/// A VP evaluator that refuses to evaluate any VP code. Native VPs under test
+/// must not call `eval`.
+#[derive(Debug)]
+pub struct NoEval;
+
+impl<'a, S, CA> VpEvaluator<'a, S, CA, NoEval> for NoEval
+where
+    S: 'a + StateRead,
+{
+    fn eval(
+        _ctx: &Ctx<'a, S, CA, NoEval>,
+        _vp_code_hash: Hash,
+        _input_data: BatchedTxRef<'_>,
+    ) -> Result<()> {
+        Err(Error::SimpleMessage(
+            "VP evaluation is not supported by the native VP test harness",
+        ))
+    }
+}
+
+/// The native VP context used by [`run_native_vp`].
+pub type TestCtx<'a> = Ctx<'a, TestState, (), NoEval>;
+
+/// Apply the given `tx_changes` to the write log of the `pre_state` and run the
+/// native `vp` of the account `address` against them. A change with no value
+/// deletes the key. The keys changed and the verifiers passed to the VP are
+/// derived from the write log.
+pub fn run_native_vp<VP>(
+    vp: VP,
+    address: &Address,
+    pre_state: &mut TestState,
+    tx_changes: impl IntoIterator<Item = (Key, Option<Vec<u8>>)>,
+) -> Result<()>
+where
+    VP: for<'ctx> FnOnce(
+        &'ctx TestCtx<'ctx>,
+        &BatchedTxRef<'_>,
+        &BTreeSet<Key>,
+        &BTreeSet<Address>,
+    ) -> Result<()>,
+{
+    for (key, value) in tx_changes {
+        let write_log = pre_state.write_log_mut();
+        let _ = match value {
+            Some(value) => write_log.write(&key, value),
+            None => write_log.delete(&key),
+        }
+        .into_storage_result()?;
+    }
+    let state = &*pre_state;
+    let (verifiers, keys_changed) = state
+        .write_log()
+        .verifiers_and_changed_keys(&BTreeSet::new());
+
+    let mut tx = Tx::from_type(TxType::Raw);
+    tx.header.chain_id = state.in_mem().chain_id.clone();
+    let cmt = TxCommitments::default();
+    let tx_index = TxIndex::default();
+    let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+        &TxGasMeter::new(u64::MAX, 1),
+    ));
+    let ctx = TestCtx::new(
+        address,
+        state,
+        &tx,
+        &cmt,
+        &tx_index,
+        &gas_meter,
+        &keys_changed,
+        &verifiers,
+        (),
+    );
+    let batched_tx = BatchedTxRef { tx: &tx, cmt: &cmt };
+    vp(&ctx, &batched_tx, &keys_changed, &verifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::InternalAddress;
+    use namada_core::borsh::BorshSerializeExt;
+    use namada_state::StorageWrite;
+
+    use super::*;
+    use crate::state::StorageRead;
+
+    const ADDRESS: Address = Address::Internal(InternalAddress::Parameters);
+
+    #[test]
+    fn test_run_always_accept_vp() {
+        let mut state = TestState::default();
+        let key = Key::parse("key").unwrap();
+
+        let result = run_native_vp(
+            |_ctx, _tx, _keys_changed, _verifiers| Ok(()),
+            &ADDRESS,
+            &mut state,
+            [(key, Some(1_u64.serialize_to_vec()))],
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_run_vp_sees_tx_changes() {
+        let mut state = TestState::default();
+        let written = Key::parse("written").unwrap();
+        let deleted = Key::parse("deleted").unwrap();
+        state.write(&deleted, 1_u64).unwrap();
+        state.commit_block().unwrap();
+
+        let result = run_native_vp(
+            |ctx, _tx, keys_changed, _verifiers| {
+                assert_eq!(
+                    keys_changed,
+                    &BTreeSet::from([deleted.clone(), written.clone()])
+                );
+                assert_eq!(ctx.pre().read::<u64>(&deleted)?, Some(1));
+                assert_eq!(ctx.post().read::<u64>(&deleted)?, None);
+                assert_eq!(ctx.pre().read::<u64>(&written)?, None);
+                assert_eq!(ctx.post().read::<u64>(&written)?, Some(2));
+                Err(Error::SimpleMessage("rejected"))
+            },
+            &ADDRESS,
+            &mut state,
+            [
+                (written.clone(), Some(2_u64.serialize_to_vec())),
+                (deleted.clone(), None),
+            ],
+        );
+        assert!(result.is_err());
+    }
+}
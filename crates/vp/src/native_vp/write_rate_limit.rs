@@ -0,0 +1,250 @@
+//! Native VP that rate-limits the storage modifications of accounts, to
+//! throttle spam.
+//!
+//! The VP is triggered for txs that modify the storage of non-internal
+//! accounts. The maximum number of modifications allowed per account per block
+//! is read from the parameters storage prior to the tx and writes are not
+//! rate-limited when it's not set. The modifications of the subspaces of
+//! internal addresses (e.g. the balances in the multitoken subspace) are not
+//! rate-limited. An event is emitted for every account that has used up its
+//! modifications for the block.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::marker::PhantomData;
+
+use namada_core::address::Address;
+use namada_events::extend::{ComposeEvent, EventAttributeEntry};
+use namada_events::{Event, EventLevel, EventToEmit};
+use namada_state::write_log::WriteLog;
+
+use super::{Ctx, VpEvaluator};
+use crate::state::{Error, Key, Result, StateRead};
+
+pub mod types {
+    //! Write rate limit event types.
+
+    use namada_events::{EventType, event_type};
+
+    use super::WriteRateLimitEvent;
+
+    /// An account reached the maximum number of storage modifications per
+    /// block.
+    pub const LIMIT_REACHED: EventType =
+        event_type!(WriteRateLimitEvent, "limit-reached");
+}
+
+/// Write rate limit event.
+#[derive(Debug)]
+pub struct WriteRateLimitEvent {
+    /// The account whose storage got modified.
+    pub account: Address,
+    /// The number of modifications of the account in the current block.
+    pub writes: u64,
+}
+
+impl EventToEmit for WriteRateLimitEvent {
+    const DOMAIN: &'static str = "write-rate-limit";
+}
+
+impl From<WriteRateLimitEvent> for Event {
+    fn from(event: WriteRateLimitEvent) -> Self {
+        Event::new(types::LIMIT_REACHED, EventLevel::Tx)
+            .with(LimitedAccount(event.account))
+            .with(AccountWrites(event.writes))
+            .into()
+    }
+}
+
+/// Extend an [`Event`] with the account that reached the write rate limit.
+pub struct LimitedAccount(pub Address);
+
+impl EventAttributeEntry<'static> for LimitedAccount {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "account";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the number of modifications of an account in the
+/// current block.
+pub struct AccountWrites(pub u64);
+
+impl EventAttributeEntry<'static> for AccountWrites {
+    type Value = u64;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "writes";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Write rate-limiting VP
+pub struct WriteRateLimitVp<'ctx, S, CA, EVAL> {
+    /// Generic types for VP context and DI
+    pub _marker: PhantomData<(&'ctx S, CA, EVAL)>,
+}
+
+impl<'ctx, S, CA, EVAL> WriteRateLimitVp<'ctx, S, CA, EVAL>
+where
+    S: StateRead,
+    CA: 'static + Clone,
+    EVAL: 'static + VpEvaluator<'ctx, S, CA, EVAL>,
+{
+    /// Run the validity predicate with the `max_writes` per account per block
+    /// read from the state prior to the tx. When it's not set, writes are not
+    /// rate-limited.
+    pub fn validate_tx(
+        ctx: &'ctx Ctx<'ctx, S, CA, EVAL>,
+        keys_changed: &BTreeSet<Key>,
+        max_writes: Option<u64>,
+    ) -> Result<()> {
+        // The VP's own subspace is never written to
+        if keys_changed
+            .iter()
+            .any(|key| key.fst_address() == Some(ctx.address))
+        {
+            return Err(Error::new_const(
+                "The write rate limit storage must not be modified",
+            ));
+        }
+        let Some(max_writes) = max_writes else {
+            return Ok(());
+        };
+        let writes = account_writes(ctx.state.write_log(), keys_changed)?;
+        for (account, &writes) in &writes {
+            if writes >= max_writes {
+                ctx.emit_event(WriteRateLimitEvent {
+                    account: account.clone(),
+                    writes,
+                })?;
+            }
+        }
+        check_writes_per_account(&writes, max_writes)
+    }
+}
+
+/// Count the modifications in the current block of the non-internal accounts
+/// whose storage got modified by the current tx. Temporary storage
+/// modifications are not counted.
+pub fn account_writes(
+    write_log: &WriteLog,
+    keys_changed: &BTreeSet<Key>,
+) -> Result<BTreeMap<Address, u64>> {
+    let owners: BTreeSet<_> = keys_changed
+        .iter()
+        .filter_map(Key::fst_address)
+        .filter(|owner| !owner.is_internal())
+        .cloned()
+        .collect();
+    Ok(write_log.block_account_writes(&owners)?)
+}
+
+/// Check that none of the accounts has exceeded `max_writes` modifications in
+/// the current block.
+pub fn check_writes_per_account(
+    writes: &BTreeMap<Address, u64>,
+    max_writes: u64,
+) -> Result<()> {
+    writes.iter().try_for_each(|(owner, &writes)| {
+        if writes > max_writes {
+            return Err(Error::new_alloc(format!(
+                "Account {owner} exceeded the maximum of {max_writes} storage \
+                 modifications per block with {writes} modifications"
+            )));
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+
+    use super::*;
+    use crate::state::KeySeg;
+
+    fn writes(write_log: &WriteLog) -> BTreeMap<Address, u64> {
+        account_writes(write_log, &write_log.get_keys()).unwrap()
+    }
+
+    fn owned_key(owner: &Address, seg: &str) -> Key {
+        Key::from(owner.to_db_key())
+            .push(&seg.to_owned())
+            .expect("Cannot obtain a storage key")
+    }
+
+    #[test]
+    fn test_writes_at_cap_accepted() {
+        let owner = established_address_1();
+        let mut write_log = WriteLog::default();
+
+        // A modification from an earlier tx in the block
+        let _ = write_log.write(&owned_key(&owner, "a"), vec![1]).unwrap();
+        write_log.commit_batch_and_current_tx();
+        let _ = write_log.write(&owned_key(&owner, "b"), vec![2]).unwrap();
+        // Writes to other accounts are counted separately
+        let _ = write_log
+            .write(&owned_key(&established_address_2(), "a"), vec![3])
+            .unwrap();
+
+        assert!(check_writes_per_account(&writes(&write_log), 2).is_ok());
+    }
+
+    #[test]
+    fn test_writes_over_cap_rejected() {
+        let owner = established_address_1();
+        let mut write_log = WriteLog::default();
+
+        // Every modification is counted, including rewrites of the same key
+        // in different txs
+        let _ = write_log.write(&owned_key(&owner, "a"), vec![1]).unwrap();
+        write_log.commit_batch_and_current_tx();
+        let _ = write_log.write(&owned_key(&owner, "a"), vec![2]).unwrap();
+        write_log.commit_batch_and_current_tx();
+        let _ = write_log.write(&owned_key(&owner, "b"), vec![3]).unwrap();
+
+        assert!(check_writes_per_account(&writes(&write_log), 2).is_err());
+    }
+
+    #[test]
+    fn test_temp_writes_ignored() {
+        let owner = established_address_1();
+        let mut write_log = WriteLog::default();
+
+        let _ = write_log.write(&owned_key(&owner, "a"), vec![1]).unwrap();
+        let _ = write_log
+            .write_temp(&owned_key(&owner, "temp_a"), vec![2])
+            .unwrap();
+        let _ = write_log
+            .write_temp(&owned_key(&owner, "temp_b"), vec![3])
+            .unwrap();
+
+        assert!(check_writes_per_account(&writes(&write_log), 1).is_ok());
+    }
+
+    #[test]
+    fn test_internal_writes_ignored() {
+        let mut write_log = WriteLog::default();
+
+        for seg in ["a", "b", "c"] {
+            let _ = write_log
+                .write(&owned_key(&address::MULTITOKEN, seg), vec![1])
+                .unwrap();
+            write_log.commit_batch_and_current_tx();
+        }
+        let _ = write_log
+            .write(&owned_key(&address::MULTITOKEN, "d"), vec![1])
+            .unwrap();
+
+        assert!(check_writes_per_account(&writes(&write_log), 1).is_ok());
+    }
+}
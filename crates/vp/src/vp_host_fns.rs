@@ -63,6 +63,7 @@ where
     let (log_val, gas) =
         state.write_log().read_pre(key).into_storage_result()?;
     add_gas(gas_meter, gas)?;
+    state.write_log().record_read(log_val.is_some());
     match log_val {
         Some(write_log::StorageModification::Write { ref value }) => {
             Ok(Some(value.clone()))
@@ -99,6 +100,7 @@ where
     // Try to read from the write log first
     let (log_val, gas) = state.write_log().read(key).into_storage_result()?;
     add_gas(gas_meter, gas)?;
+    state.write_log().record_read(log_val.is_some());
     match log_val {
         Some(write_log::StorageModification::Write { value }) => {
             Ok(Some(value.clone()))
@@ -379,6 +381,33 @@ where
     Ok(iter)
 }
 
+/// Read all the items of the posterior state (after tx execution) matching the
+/// given prefix, ordered by storage keys, charging gas for each of them. If the
+/// gas meter runs out, the iteration stops and the items read so far are
+/// returned together with the out-of-gas error.
+pub fn iter_prefix_metered<D>(
+    gas_meter: &RefCell<VpGasMeter>,
+    write_log: &WriteLog,
+    db: &D,
+    prefix: &Key,
+) -> (Vec<(String, Vec<u8>)>, Result<()>)
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    let mut items = vec![];
+    let mut iter = match iter_prefix_post(gas_meter, write_log, db, prefix) {
+        Ok(iter) => iter,
+        Err(err) => return (items, Err(err)),
+    };
+    loop {
+        match iter_next(gas_meter, &mut iter) {
+            Ok(Some(item)) => items.push(item),
+            Ok(None) => return (items, Ok(())),
+            Err(err) => return (items, Err(err)),
+        }
+    }
+}
+
 /// Get the next item in a storage prefix iterator (pre or post).
 pub fn iter_next<DB>(
     gas_meter: &RefCell<VpGasMeter>,
@@ -393,3 +422,46 @@ where
     }
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+    use namada_gas::{STORAGE_ACCESS_GAS_PER_BYTE, TxGasMeter};
+
+    use super::*;
+    use crate::state::KeySeg;
+    use crate::state::mockdb::MockDB;
+
+    #[test]
+    fn test_iter_prefix_metered_out_of_gas() {
+        let prefix = Key::from(established_address_1().to_db_key());
+        let mut write_log = WriteLog::default();
+        for i in 0_u64..4 {
+            let key = prefix.push(&i).unwrap();
+            let _ = write_log.write(&key, vec![0; 10]).unwrap();
+        }
+        let db = MockDB::default();
+
+        // Enough gas for the prefix and exactly two 10 bytes values
+        let prefix_len = prefix.len() as u64;
+        let prefix_gas =
+            checked!(prefix_len * STORAGE_ACCESS_GAS_PER_BYTE).unwrap();
+        let limit = checked!(prefix_gas + 20).unwrap();
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(limit, 1),
+        ));
+        let (items, result) =
+            iter_prefix_metered(&gas_meter, &write_log, &db, &prefix);
+        assert_eq!(items.len(), 2);
+        assert!(result.is_err());
+
+        // With a sufficient limit all the items are read
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(checked!(limit + 20).unwrap(), 1),
+        ));
+        let (items, result) =
+            iter_prefix_metered(&gas_meter, &write_log, &db, &prefix);
+        assert_eq!(items.len(), 4);
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,17 @@
+import json
+import glob
+import hashlib
+import os
+
+CURRENT_FOLDER = os.path.dirname(os.path.realpath(__file__))
+WASM_FOLDER = "{}/../wasm_for_tests".format(CURRENT_FOLDER)
+CHECKSUM_FILE_PATH = "{}/checksums.json".format(WASM_FOLDER)
+
+
+checksums = {}
+for wasm in sorted(glob.glob("{}/*.wasm".format(WASM_FOLDER))):
+    basename = os.path.basename(wasm)
+    checksums[basename] = hashlib.sha256(open(wasm, "rb").read()).hexdigest()
+
+with open(CHECKSUM_FILE_PATH, 'w+') as f:
+    json.dump(checksums, f, indent=4, sort_keys=True)